@@ -1,6 +1,9 @@
 pub mod auction;
+pub mod clock;
 mod client;
 mod exit;
+pub mod schedule;
+mod shared;
 mod util;
 
 use std::{
@@ -8,33 +11,48 @@ use std::{
     thread::{Builder, current},
     time::{Duration, Instant},
 };
-use humantime::{format_duration, FormattedDuration};
+use humantime::{format_duration, parse_duration, FormattedDuration};
 use parking_lot::Mutex;
 use smol::{block_on, Timer};
 use spin_sleep::sleep;
 use twitchchat::{
     connector::smol::Connector,
-    messages::{Commands, Privmsg},
+    messages::{Commands, Privmsg, Whisper},
     runner::AsyncRunner,
     Status,
     twitch::UserConfigError,
     UserConfig,
 };
-use crate::ConfigFile;
-use auction::{Auction, Bid, BidResult};
+use crate::{
+    ConfigFile,
+    config::ConfigPreset,
+    currency::{Currency, Money},
+    saving::{Ledger, Snapshot, SnapshotWriter},
+};
+use auction::{
+    Auction, AuctionMode, Bid, BidResult, BitsMode, Closure, PauseReason,
+    ProxyResult, Winner,
+};
 use client::{Client, Response};
 pub use exit::BotExit;
-pub use util::{is_quoted, split_cmd, substring_to_end, to_end_unquoted, unquote};
+use clock::{Clock, SystemClock};
+use schedule::{AuctionSetup, parse_when, Scheduled, WhenError};
+use shared::{Addressee, answers_whispers, Group};
+pub use util::{
+    BidAmount, Cooldowns, is_quoted, split_cmd, substring_to_end, to_end_unquoted,
+    unquote,
+};
+use util::parse_times;
 
 
 /// Define the values of remaining time at which an update on the auction should
-///     be posted to chat automatically.
+///     be posted to chat automatically, unless another schedule is configured.
 const fn announce_time(sec: u64) -> bool {
     match sec {
         10 | 15 | 30 | 60 // <=1m
         | 120 | 300 | 600 | 900 | 1800 | 3600 // <=1h
         => true,
-        _ if 0 == sec % 3600 => {
+        _ if sec.is_multiple_of(3600) => {
             match sec / 3600 {
                 1..=24 // <=1d
                 | 36 | 48 | 72 // <=3d
@@ -49,25 +67,59 @@ const fn announce_time(sec: u64) -> bool {
 }
 
 
-enum AuctionStatus {
+/// Determine whether the time left in an Auction should be announced, with the
+///     given number of seconds remaining.
+fn announce_due(auction: &Auction, sec: u64) -> bool {
+    match &auction.options.announce_at {
+        Some(times) => times.iter().any(|time| time.as_secs() == sec),
+        None => announce_time(sec),
+    }
+}
+
+
+/// The state of an Auction after a check, along with anything to be announced.
+pub enum AuctionStatus {
     Active(Option<String>),
-    Ended(String, Auction),
+    Ended(String, Box<Auction>),
     Inactive,
 }
 
 
-fn auction_check(lock: &mut Option<Auction>) -> AuctionStatus {
+/// Advance the Auction, if there is one, according to its Clock. This is done
+///     once per second by the Auction thread. An Auction that has ended is
+///     removed, and returned with the announcement of its winner.
+pub fn auction_check(lock: &mut Option<Auction>) -> AuctionStatus {
     use AuctionStatus::*;
 
     match lock {
+        //  Nothing is announced while paused, and the Auction cannot end.
+        Some(auction) if auction.paused().is_some() => Active(None),
         Some(auction) => match auction.remaining() {
+            Some(..) if auction.options.mode == AuctionMode::Dutch => {
+                match auction.update_price() {
+                    Some(price) => Active(Some(format!(
+                        "PRICE: The {} is now at {}.",
+                        auction.describe(), auction.money(price),
+                    ))),
+                    None => Active(None),
+                }
+            }
             Some(time) => match time.as_secs() + 1 {
-                t @ 1..=5 => Active(Some(format!("Auction: {}...", t))),
+                t if t <= auction.options.countdown => {
+                    Active(Some(format!("Auction: {}...", t)))
+                }
 
-                t if announce_time(t) => match auction.last_bid() {
+                t if announce_due(auction, t) => match auction.last_bid() {
+                    _ if auction.options.mode == AuctionMode::Sealed => {
+                        Active(Some(format!(
+                            "Auction: {} seconds remain to submit a sealed \
+                            bid{}.",
+                            t, auction.for_prize(),
+                        )))
+                    }
                     Some(Bid { amount, .. }) => Active(Some(format!(
                         "Auction: {} seconds remain. The current bid{} is {}.",
-                        t, auction.for_prize(), money!(amount),
+                        t, auction.for_prize(), auction.money(*amount),
                     ))),
                     None => Active(Some(match auction.prize.as_ref() {
                         Some(prize) => format!(
@@ -81,19 +133,60 @@ fn auction_check(lock: &mut Option<Auction>) -> AuctionStatus {
                 _ => Active(None),
             }
             None => {
-                let out: String = match auction.last_bid() {
-                    Some(Bid { amount, bidder, .. }) => format!(
+                let mut out: String = match auction.winner() {
+                    Some(Winner { name, price_paid, .. })
+                    if auction.closure == Some(Closure::BuyNow) => format!(
+                        "BUY IT NOW: The {} has been won instantly by @{}, \
+                        at the price of {}.",
+                        auction.describe(), name, auction.money(price_paid),
+                    ),
+                    None if auction.leading_bid().is_none() => match auction.options.mode {
+                        AuctionMode::Dutch => format!(
+                            "The {} has ended without a buyer.",
+                            auction.describe(),
+                        ),
+                        _ => format!(
+                            "The {} has ended with no bids.",
+                            auction.describe(),
+                        ),
+                    },
+                    None if !auction.reserve_met() => format!(
+                        "The {} has ended, but the reserve price was not met. \
+                        There is no winner.",
+                        auction.describe(),
+                    ),
+                    Some(Winner { name, amount, price_paid, .. })
+                    if amount != price_paid => format!(
+                        "The {} has been won by @{}, with a bid of {}. The \
+                        price to be paid is {}.",
+                        auction.describe(), name, auction.money(amount),
+                        auction.money(price_paid),
+                    ),
+                    Some(Winner { name, amount, .. }) => format!(
                         "The {} has been won by @{}, with a bid of {}.",
-                        auction.describe(), bidder, money!(amount),
+                        auction.describe(), name, auction.money(amount),
                     ),
                     None => format!(
-                        "The {} has ended with no bids.",
+                        "The {} has ended without a winner.",
                         auction.describe(),
                     ),
                 };
 
+                if let Some(Winner { bits: Some(bits), price_paid, .. }) = auction.winner() {
+                    if auction.options.bits == BitsMode::Deposit {
+                        let deposit: Money = auction.bits_value(bits);
+
+                        out.push_str(&format!(
+                            " The {} Bits cheered with the bids count for {}, \
+                            leaving {} to be paid.",
+                            bits, auction.money(deposit),
+                            auction.money(price_paid.saturating_sub(deposit)),
+                        ));
+                    }
+                }
+
                 info!("Auction finished.");
-                Ended(out, lock.take().unwrap())
+                Ended(out, Box::new(lock.take().unwrap()))
             }
         }
         None => Inactive,
@@ -101,25 +194,339 @@ fn auction_check(lock: &mut Option<Auction>) -> AuctionStatus {
 }
 
 
+/// Start the next scheduled Auction if it is due, or announce it if one of its
+///     lead times has been reached. A scheduled Auction waits for any running
+///     Auction to end before it starts.
+fn schedule_check(
+    lock: &mut Option<Auction>,
+    queue: &mut Vec<Scheduled>,
+    leads: &[Duration],
+    prefix: &str,
+) -> Option<String> {
+    let next: &mut Scheduled = queue.first_mut()?;
+
+    if next.is_due() {
+        if lock.is_some() {
+            return None;
+        }
+
+        let Scheduled { setup, by, .. } = queue.remove(0);
+        info!("Scheduled Auction started (scheduled by {}).", by);
+        let (auction, verb) = setup.begin();
+        let new: &mut Auction = lock.insert(auction);
+
+        return Some(new.explain(prefix, &verb));
+    }
+
+    let until: Duration = next.announce(leads)?;
+
+    Some(format!(
+        "UPCOMING: The {} will begin in {}.",
+        next.setup.describe(), format_duration(until),
+    ))
+}
+
+
+/// Collect the names of the Twitch badges attached to a chat message.
+fn badges<'a>(msg: &'a Privmsg<'_>) -> Vec<&'a str> {
+    msg.tags().get("badges")
+        .map(|tag| tag.split(',')
+            .filter_map(|badge| badge.split('/').next())
+            .filter(|name| !name.is_empty())
+            .collect())
+        .unwrap_or_default()
+}
+
+
+/// Save the Summary file of a finished Auction, and add it to the CSV file, if
+///     there is one.
+fn save_summary(
+    auction: &Auction,
+    channel: &str,
+    #[cfg(feature = "csv")] opt_csv: Option<&std::path::Path>,
+) {
+    let finished = auction.finish();
+
+    if let Err(e) = finished.save(channel) {
+        warn!("Failed to save Auction data: {}", e);
+    }
+
+    #[cfg(feature = "csv")]
+    if let Some(path) = opt_csv {
+        if let Err(e) = finished.save_csv(path) {
+            warn!("Failed to write CSV: {}", e);
+        }
+    }
+}
+
+
+/// Count a refused bid, and decide whether it should still get a reply. Once
+///     the cap on replies is reached, refusals are counted, but not answered.
+pub fn reply_to_rejection(auction: &mut Auction, cap: Option<usize>) -> bool {
+    auction.rejected += 1;
+
+    match cap {
+        Some(cap) if cap < auction.rejected => {
+            auction.muted += 1;
+            false
+        }
+        _ => true,
+    }
+}
+
+
+/// Explain to a user who bid by whisper that they must bid in chat instead,
+///     so that their badges can be checked.
+fn restricted_whisper(auction: &Auction) -> String {
+    format!(
+        "Bids in this {} are limited by badge, which can only be checked in \
+        chat. {} Please bid in the channel instead.",
+        auction.describe(), auction.options.eligible.explain(),
+    )
+}
+
+
+/// Explain the form that a bid must take in the given currency.
+fn invalid_amount(currency: &Currency) -> String {
+    let rule: String = match currency.decimals {
+        0 => format!("A bid must be a positive whole number of {}.", currency.code),
+        n => format!(
+            "A bid must be a positive amount of {}, with no more than {} \
+            decimal places.",
+            currency.code, n,
+        ),
+    };
+
+    format!(
+        "{} It may also be written like '1.5k', '+10' to raise the current \
+        bid, or 'min' for the lowest bid allowed.",
+        rule,
+    )
+}
+
+
+/// Describe where a running Auction stands, for chat, when it continues after
+///     an interruption.
+fn progress(auction: &Auction) -> String {
+    let status = match auction.last_bid() {
+        _ if auction.options.mode == AuctionMode::Dutch => format!(
+            "The current price is {}",
+            auction.money(auction.price().unwrap_or_default()),
+        ),
+        Some(..) if auction.options.mode == AuctionMode::Sealed => {
+            String::from("All bids remain sealed")
+        }
+        Some(Bid { amount, bidder, .. }) => format!(
+            "The highest bidder is currently @{} at {}",
+            bidder, auction.money(*amount),
+        ),
+        None => format!(
+            "The minimum bid is {}",
+            auction.money(auction.options.min_bid),
+        ),
+    };
+
+    let time = format_duration(match auction.remaining() {
+        Some(time) => Duration::from_secs(time.as_secs() + 1),
+        None => Duration::from_secs(0),
+    });
+
+    match auction.paused() {
+        Some(..) => format!(
+            "{}, with {} remaining once an operator resumes it",
+            status, time,
+        ),
+        None => format!("{}, with {} remaining", status, time),
+    }
+}
+
+
+/// Announce bids that were placed automatically, exactly as though they had
+///     been placed by hand.
+fn announce_bids(auction: &Auction, bids: &[(String, Money)]) -> String {
+    bids.iter()
+        .map(|(name, amount)| format!(
+            "NEW BID: @{} has bid {}.",
+            name, auction.money(*amount),
+        ))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+
+fn proxy_response(auction: &Auction, max: Money, result: ProxyResult) -> Response {
+    use Response::*;
+
+    match result {
+        ProxyResult::Set { proxied } if proxied.is_empty() => Reply(format!(
+            "Your maximum bid of {} has been registered.",
+            auction.money(max),
+        )),
+        ProxyResult::Set { proxied } => Message(announce_bids(auction, &proxied)),
+        ProxyResult::Closed => Reply(format!(
+            "The {} has already ended.",
+            auction.describe(),
+        )),
+        ProxyResult::Ineligible => Reply(format!(
+            "You are not eligible to bid in this {}. {}",
+            auction.describe(), auction.options.eligible.explain(),
+        )),
+        ProxyResult::Paused => Reply(format!(
+            "The {} is paused. Maximum bids will be accepted again when it \
+            resumes.",
+            auction.describe(),
+        )),
+        ProxyResult::TooLow(min) => Reply(format!(
+            "Your maximum bid must be at least {}.",
+            auction.money(min),
+        )),
+        ProxyResult::AboveBalance(balance) => Reply(format!(
+            "Your maximum bid may not be more than your balance of {}.",
+            auction.money(balance),
+        )),
+        ProxyResult::Unavailable => Reply(String::from(
+            "Maximum bids can only be used in an open Auction.",
+        )),
+    }
+}
+
+
+fn bid_response(
+    auction: &Auction,
+    author: &str,
+    bid: Money,
+    result: BidResult,
+) -> Response {
+    use Response::*;
+
+    match result {
+        BidResult::Ok { first, proxied } => Message(format!(
+            "{} BID: @{} has bid {}.{}",
+            if first { "FIRST" } else { "NEW" },
+            author,
+            auction.money(bid),
+            match proxied.is_empty() {
+                true => String::new(),
+                false => format!(" {}", announce_bids(auction, &proxied)),
+            },
+        )),
+        //  A Reply is seen by the whole chat, so it must not reveal the bid.
+        BidResult::Sealed { revised: false } => Reply(String::from(
+            "Your sealed bid has been recorded.",
+        )),
+        BidResult::Sealed { revised: true } => Reply(String::from(
+            "Your sealed bid has been replaced.",
+        )),
+        BidResult::Bought(price) => Message(format!(
+            "SOLD: @{} has accepted the price of {}.",
+            author,
+            auction.money(price),
+        )),
+        BidResult::BuyNow(price) => Message(format!(
+            "BUY IT NOW: @{} has bid {}.",
+            author,
+            auction.money(price),
+        )),
+        BidResult::Closed => Reply(format!(
+            "The {} has already ended.",
+            auction.describe(),
+        )),
+        BidResult::Ineligible => Reply(format!(
+            "You are not eligible to bid in this {}. {}",
+            auction.describe(), auction.options.eligible.explain(),
+        )),
+        BidResult::Paused => Reply(format!(
+            "The {} is paused. Bids will be accepted again when it resumes.",
+            auction.describe(),
+        )),
+        BidResult::BelowPrice(price) => Reply(format!(
+            "The current price is {}.",
+            auction.money(price),
+        )),
+        BidResult::RepeatBidder(bid) => Reply(format!(
+            "You are already the top bidder at {}.",
+            auction.money(bid),
+        )),
+        BidResult::AboveMaximum(max) => Reply(format!(
+            "You can only raise by a maximum of {}.",
+            auction.money(max),
+        )),
+        BidResult::AboveBalance(balance) => Reply(format!(
+            "You may not bid more than your balance of {}.",
+            auction.money(balance),
+        )),
+        BidResult::AboveOpening(max) => Reply(match auction.options.mode {
+            AuctionMode::Open | AuctionMode::Dutch => format!(
+                "The first bid may not be more than {}.",
+                auction.money(max),
+            ),
+            AuctionMode::Sealed => format!(
+                "A sealed bid may not be more than {}.",
+                auction.money(max),
+            ),
+        }),
+        BidResult::BelowIncrement(next) => Reply(format!(
+            "Bids must be raised by at least {}. The next bid must be at \
+            least {}.",
+            auction.options.min_raise.format(&auction.options.currency),
+            auction.money(next),
+        )),
+        BidResult::BelowMinimum(min) => Reply(format!(
+            "The minimum bid is {}.",
+            auction.money(min),
+        )),
+        BidResult::DoesNotRaise(cur) => Reply(format!(
+            "The current bid is {}.",
+            auction.money(cur),
+        )),
+    }
+}
+
+
 pub struct Bot {
     channel: String,
     config: ConfigFile,
     client: Option<Client>,
+    /// The Channels with which this one shares its Auction, if any.
+    group: Arc<Group>,
     auction: Arc<Mutex<Option<Auction>>>,
-    stopped: Option<Instant>,
+    /// The most recent Auction to have ended, kept in case its winner must be
+    ///     replaced.
+    finished: Arc<Mutex<Option<Auction>>>,
+    /// Auctions waiting to be started automatically, in order of their start.
+    schedule: Arc<Mutex<Vec<Scheduled>>>,
+    /// The source of the current time for Auctions.
+    clock: Arc<dyn Clock>,
+    /// The latest bid attempt by each user.
+    cooldowns: Cooldowns,
+    /// Whether the data directory has been checked for an Auction that was
+    ///     interrupted when the bot last stopped.
+    checked_snapshot: bool,
 }
 
 impl Bot {
     pub fn new(channel: String, config: ConfigFile) -> Self {
+        let group: Arc<Group> = Group::join(&config.auction_id(&channel), &channel);
+
         Self {
             channel,
             config,
             client: None,
-            auction: Default::default(),
-            stopped: None,
+            auction: group.auction.clone(),
+            finished: group.finished.clone(),
+            schedule: group.schedule.clone(),
+            group,
+            clock: SystemClock::shared(),
+            cooldowns: Cooldowns::default(),
+            checked_snapshot: false,
         }
     }
 
+    /// Run Auctions on a different Clock than the system clock.
+    pub fn with_clock(self, clock: Arc<dyn Clock>) -> Self {
+        Self { clock, ..self }
+    }
+
     fn authenticate(&self, msg: &Privmsg<'_>) -> bool {
         self.config.is_admin(msg.name(), msg.channel().trim_start_matches('#'))
             || msg.is_broadcaster()
@@ -130,6 +537,348 @@ impl Bot {
         self.config.is_blacklisted(msg.name(), msg.channel().trim_start_matches('#'))
     }
 
+    /// Read the options given to start an Auction, on top of the defaults
+    ///     configured for the channel. A preset, if one is named, is applied
+    ///     before any other flags, wherever it appears.
+    fn auction_setup(&self, channel: &str, args: &[&str]) -> Result<AuctionSetup, String> {
+        let mut opt = self.config.auction_options(channel);
+        let mut vrb = self.config.verb(channel);
+        let mut tok = args.iter();
+        let mut prz = None;
+
+        if let Some(name) = args.windows(2).rev().find_map(|pair| match pair {
+            ["--preset", name] => Some(unquote(name)),
+            _ => None,
+        }) {
+            let preset: &ConfigPreset = match self.config.preset(channel, name) {
+                Some(preset) => preset,
+                None => return Err(format!(
+                    "There is no preset named '{}'. See '{}config presets'.",
+                    name, self.config.prefix(),
+                )),
+            };
+
+            preset.apply(&mut opt);
+            vrb = preset.verb().unwrap_or(vrb);
+            prz = preset.prize();
+        }
+
+        while let Some(flag) = tok.next() {
+            match *flag {
+                "--mode"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.mode = vl;
+                    }
+                }
+                "--buynow" | "--buy-now"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = opt.currency.parse(val) {
+                        opt.buy_now = Some(vl);
+                    }
+                }
+                "--reserve"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = opt.currency.parse(val) {
+                        opt.reserve = Some(vl);
+                    }
+                }
+                "--eligible"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = unquote(val).parse() {
+                        opt.eligible = vl;
+                    }
+                }
+                "--settle" | "--settlement"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.settlement = vl;
+                    }
+                }
+                "--start"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = opt.currency.parse(val) {
+                        opt.price_start = Some(vl);
+                    }
+                }
+                "--floor"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = opt.currency.parse(val) {
+                        opt.price_floor = Some(vl);
+                    }
+                }
+                "--step"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = opt.currency.parse(val) {
+                        opt.price_step = vl;
+                    }
+                }
+                "--interval"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.price_interval = Duration::from_secs(vl);
+                    }
+                }
+                "-d" | "-t" | "--time"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.duration = Duration::from_secs(vl);
+                    }
+                }
+                "-h" | "--helm" | "--helmet"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.helmet = Duration::from_secs(vl);
+                    }
+                }
+                "--helmet-policy"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.helmet_policy = vl;
+                    }
+                }
+                "--helmet-add"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.helmet_extension = Some(Duration::from_secs(vl));
+                    }
+                }
+                "--helmet-cap"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.helmet_max_total = Some(Duration::from_secs(vl));
+                    }
+                }
+                "--helmet-count"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.helmet_max_count = Some(vl);
+                    }
+                }
+                "--announce"
+                => if let Some(val) = tok.next() {
+                    match unquote(val) {
+                        "off" | "none" => {
+                            opt.announce_at = Some(Vec::new());
+                            opt.countdown = 0;
+                        }
+                        "default" => opt.announce_at = None,
+                        list => if let Some(vl) = parse_times(list) {
+                            opt.announce_at = Some(vl);
+                        }
+                    }
+                }
+                "--countdown"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.countdown = vl;
+                    }
+                }
+                "--bits"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.bits = vl;
+                    }
+                }
+                "--bit-value"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = opt.currency.parse(val) {
+                        opt.bit_value = vl;
+                    }
+                }
+                "-r" | "--raise" | "--limit"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = opt.currency.parse(val) {
+                        opt.max_raise = vl;
+                    }
+                }
+                "-i" | "--increment" | "--min-raise"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.min_raise = vl;
+                    }
+                }
+                "-m" | "--min"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = opt.currency.parse(val) {
+                        opt.min_bid = vl;
+                    }
+                }
+                "-o" | "--opening"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = opt.currency.parse(val) {
+                        opt.max_opening_bid = Some(vl);
+                    }
+                }
+                "--enforce-first"
+                => if let Some(val) = tok.next() {
+                    if let Ok(vl) = val.parse() {
+                        opt.enforce_max_raise_on_first = vl;
+                    }
+                }
+                "-v" | "--verb"
+                => if let Some(val) = tok.next() {
+                    vrb = val;
+                }
+                "--prize" => {
+                    prz = tok.next().copied();
+                }
+                "--preset" => {
+                    tok.next();
+                }
+                _ => {}
+            }
+        }
+
+        if opt.mode == AuctionMode::Dutch {
+            if opt.price_start.is_none() {
+                return Err(String::from(
+                    "A Dutch Auction needs a starting price; Specify one with \
+                    '--start <amount>'.",
+                ));
+            }
+
+            opt.price_interval = opt.price_interval.max(Duration::from_secs(1));
+
+            match opt.dutch_length() {
+                Some(len) if self.clock.now().checked_add(len).is_some() => {}
+                None if opt.price_step == Money::ZERO => return Err(String::from(
+                    "The price of a Dutch Auction must drop by more than zero; \
+                    Specify a step with '--step <amount>'.",
+                )),
+                _ => return Err(String::from(
+                    "The price of this Dutch Auction would take too long to fall \
+                    to its floor; Use a larger '--step' or a shorter \
+                    '--interval'.",
+                )),
+            }
+        }
+
+        if let Some(price) = opt.buy_now {
+            if price < opt.min_bid {
+                return Err(format!(
+                    "The buy-it-now price may not be less than the minimum bid \
+                    of {}.",
+                    opt.currency.format(opt.min_bid),
+                ));
+            }
+        }
+
+        if !opt.raises_possible() {
+            return Err(format!(
+                "The minimum raise of {} is more than the maximum raise of {}, \
+                so no bid could ever be raised.",
+                opt.min_raise.format(&opt.currency),
+                opt.currency.format(opt.max_raise),
+            ));
+        }
+
+        let ledger = match self.config.wallet(channel) {
+            true => match self.group.ledger() {
+                Some(ledger) => Some(ledger),
+                None => return Err(String::from(
+                    "The balances of bidders could not be read, so the Auction \
+                    cannot check them. See the log for details.",
+                )),
+            },
+            false => None,
+        };
+
+        Ok(AuctionSetup {
+            options: opt,
+            prize: prz.map(|s| String::from(unquote(s))),
+            verb: vrb.to_owned(),
+            channels: match self.group.is_shared() {
+                true => self.group.channels(),
+                false => Vec::new(),
+            },
+            ledger,
+            clock: self.clock.clone(),
+        })
+    }
+
+    /// Get the Ledger of balances, if bidders in this Channel use wallets.
+    fn ledger(&self) -> Option<Arc<Mutex<Ledger>>> {
+        match self.config.wallet(&self.channel) {
+            true => self.group.ledger(),
+            false => None,
+        }
+    }
+
+    /// Look for an Auction that was interrupted when the bot last stopped, the
+    ///     first time this is called. Returns a message for chat if one is
+    ///     found: It is either recovered immediately, or offered to operators.
+    fn check_snapshot(&mut self) -> Option<String> {
+        if std::mem::replace(&mut self.checked_snapshot, true) {
+            return None;
+        }
+
+        if self.auction.lock().is_some() {
+            return None;
+        }
+
+        let snapshot: Snapshot = match Snapshot::load(&self.config.auction_id(&self.channel)) {
+            Ok(snapshot) => snapshot?,
+            Err(e) => {
+                err!("Failed to read Snapshot: {}", e);
+                return None;
+            }
+        };
+
+        match self.config.auto_recover(&self.channel) {
+            true => Some(self.recover(snapshot).unwrap_or_else(|e| e)),
+            false => Some(format!(
+                "An unfinished {} was found from before the bot last stopped. \
+                An operator may use '{}auction recover' to continue it.",
+                snapshot.auction.describe(), self.config.prefix(),
+            )),
+        }
+    }
+
+    /// Continue an Auction from its Snapshot. Returns the announcement for
+    ///     chat, or the reason it could not be recovered.
+    fn recover(&self, snapshot: Snapshot) -> Result<String, String> {
+        let ledger = match self.config.wallet(&self.channel) {
+            true => match self.group.ledger() {
+                Some(ledger) => Some(ledger),
+                None => return Err(String::from(
+                    "The balances of bidders could not be read, so the Auction \
+                    cannot be recovered. See the log for details.",
+                )),
+            },
+            false => None,
+        };
+
+        let mut auction: Auction = snapshot.restore(self.clock.clone());
+        auction.ledger = ledger;
+
+        let text: String = format!(
+            "RECOVERED: The {} was interrupted when the bot stopped, but it \
+            can continue from where it left off. {}.",
+            auction.describe(), progress(&auction),
+        );
+
+        info!("Auction in #{} recovered.", self.channel);
+        *self.auction.lock() = Some(auction);
+        Ok(text)
+    }
+
+    fn auction_mode(&self) -> Option<AuctionMode> {
+        self.auction.lock().as_ref().map(|auction| auction.options.mode)
+    }
+
+    /// Check whether a user is attempting to bid again too soon after their
+    ///     last attempt.
+    fn should_throttle(&mut self, name: &str) -> bool {
+        let cooldown: Duration = self.config.bid_cooldown(&self.channel);
+        let begin: Instant = match self.auction.lock().as_ref() {
+            Some(auction) => auction.time_begin,
+            None => return false,
+        };
+
+        self.cooldowns.throttle(name, cooldown, begin, self.clock.now())
+    }
+
     pub fn run(&mut self) -> Result<(), String> {
         use UserConfigError::*;
 
@@ -169,47 +918,46 @@ impl Bot {
         let mut client = Client::new(self.channel.clone(), &mut runner).await?;
         info!("Connected to #{}.", self.channel);
 
-        if let Some(stopped) = self.stopped.take() {
-            if let Some(mut lock) = self.auction.try_lock() {
-                if let Some(auction) = lock.as_mut() {
-                    let downtime = Instant::now() - stopped;
-
-                    auction.add_time(downtime);
+        let notice: Option<String> = match self.auction.lock().as_mut() {
+            //  A pause set by an operator outlasts the disconnection.
+            Some(auction) if auction.paused() == Some(PauseReason::Disconnect) => {
+                auction.resume();
 
-                    let status = match auction.last_bid() {
-                        Some(Bid { amount, bidder, .. }) => format!(
-                            "The highest bidder is currently @{} at {}",
-                            bidder, money!(amount),
-                        ),
-                        None => format!(
-                            "The minimum bid is {}",
-                            money!(auction.min_bid),
-                        ),
-                    };
+                Some(format!(
+                    "Sorry, it seems I lost connection for a moment. No \
+                    problem though, I can continue the {} from where it \
+                    left off. {}.",
+                    auction.describe(), progress(auction),
+                ))
+            }
+            _ => None,
+        };
 
-                    let time = format_duration(match auction.remaining() {
-                        Some(time) => Duration::from_secs(time.as_secs() + 1),
-                        None => Duration::from_secs(0),
-                    });
+        let notice: Option<String> = match notice {
+            Some(text) => Some(text),
+            None => self.check_snapshot(),
+        };
 
-                    client.send(format!(
-                        "Sorry, it seems I lost connection for a moment. No \
-                        problem though, I can continue the {} from where it \
-                        left off. {}, with {} remaining.",
-                        auction.describe(), status, time,
-                    )).await?;
-                }
-            }
+        if let Some(text) = notice {
+            client.send(text).await?;
         }
 
+        self.group.connect(client.clone());
+
         let auction_thread = {
             let mut cli: Client = client.clone();
             let auction: Arc<Mutex<Option<Auction>>> = self.auction.clone();
+            let finished: Arc<Mutex<Option<Auction>>> = self.finished.clone();
+            let schedule: Arc<Mutex<Vec<Scheduled>>> = self.schedule.clone();
+            let group: Arc<Group> = self.group.clone();
             let running: Arc<AtomicBool> = run_thread.clone();
 
             let channel: String = self.channel.clone();
             let subname: String = format!("#{}/auctions", channel);
             let summary: bool = self.config.summary(&channel);
+            let leads: Vec<Duration> = self.config.schedule_announce(&channel);
+            let prefix: String = self.config.prefix().to_owned();
+            let mut snapshots = SnapshotWriter::new(self.config.auction_id(&channel));
 
             #[cfg(feature = "csv")]
             let opt_csv = self.config.file_csv().map(|p| p.to_owned());
@@ -223,12 +971,24 @@ impl Bot {
                 let mut time = Instant::now();
 
                 while crate::running() && cli.is_running() && running.load(SeqCst) {
-                    if let Some(mut lock) = auction.try_lock_for(TIMEOUT) {
+                    //  Only one Channel keeps time for a shared Auction, and
+                    //      relays its announcements to the others.
+                    let lock = match group.leads(&channel) {
+                        true => auction.try_lock_for(TIMEOUT),
+                        false => None,
+                    };
+
+                    if let Some(mut lock) = lock {
+                        let mut send = |text: &str| block_on(async {
+                            cli.send(text).await?;
+                            group.relay(&channel, text).await;
+                            std::io::Result::Ok(())
+                        });
                         let status = auction_check(&mut lock);
 
                         if let AuctionStatus::Active(Some(text))
                         | AuctionStatus::Ended(text, _) = &status {
-                            if let Err(e) = block_on(cli.send(text)) {
+                            if let Err(e) = send(text) {
                                 err!(
                                     "Error on Auction thread {:?}: {}",
                                     current().name().unwrap_or_default(), e,
@@ -237,20 +997,29 @@ impl Bot {
                             }
                         }
 
-                        if let AuctionStatus::Ended(_, auct) = status {
+                        if let AuctionStatus::Ended(_, mut auct) = status {
+                            auct.charge_winner();
+
                             if summary {
-                                let finished = auct.finish();
+                                #[cfg(feature = "csv")]
+                                save_summary(&auct, &channel, opt_csv.as_deref());
+                                #[cfg(not(feature = "csv"))]
+                                save_summary(&auct, &channel);
+                            }
 
-                                if let Err(e) = finished.save(&channel) {
-                                    warn!("Failed to save Auction data: {}", e);
-                                }
+                            *finished.lock() = Some(*auct);
+                        }
 
-                                #[cfg(feature = "csv")]
-                                if let Some(path) = &opt_csv {
-                                    if let Err(e) = finished.save_csv(path) {
-                                        warn!("Failed to write CSV: {}", e);
-                                    }
-                                }
+                        let text = schedule_check(&mut lock, &mut schedule.lock(), &leads, &prefix);
+                        snapshots.update(lock.as_ref());
+
+                        if let Some(text) = text {
+                            if let Err(e) = send(&text) {
+                                err!(
+                                    "Error on Auction thread {:?}: {}",
+                                    current().name().unwrap_or_default(), e,
+                                );
+                                break;
                             }
                         }
                     }
@@ -295,7 +1064,15 @@ impl Bot {
 
         running.store(false, SeqCst);
         self.client = None;
-        self.stopped = Some(Instant::now());
+
+        //  Nobody can bid while the bot is away, so the Auction waits for it,
+        //      unless another Channel sharing it is still connected.
+        if !self.group.disconnect(&self.channel) {
+            if let Some(auction) = self.auction.lock().as_mut() {
+                auction.pause(PauseReason::Disconnect, None);
+            }
+        }
+
         bot_exit
     }
 
@@ -319,12 +1096,34 @@ impl Bot {
                 );
 
                 Some(Reply(match auction.last_bid() {
+                    _ if auction.paused().is_some() => format!(
+                        "The {} is paused, with {} remaining.",
+                        auction.describe(),
+                        time,
+                    ),
+                    _ if auction.options.mode == AuctionMode::Dutch => format!(
+                        "The {} still has {} remaining. The current price \
+                        is {}, and it will drop by {} every {}, down to {}.",
+                        auction.describe(),
+                        time,
+                        auction.money(auction.price().unwrap_or_default()),
+                        auction.money(auction.options.price_step),
+                        format_duration(auction.options.price_interval),
+                        auction.money(auction.options.price_floor()),
+                    ),
+                    _ if auction.options.mode == AuctionMode::Sealed => format!(
+                        "The {} still has {} remaining. The minimum bid \
+                        is {}, and all bids are sealed until the end.",
+                        auction.describe(),
+                        time,
+                        auction.money(auction.options.min_bid),
+                    ),
                     None => format!(
                         "The {} still has {} remaining. The minimum bid \
                         is {}, but there have not been any bids yet.",
                         auction.describe(),
                         time,
-                        money!(auction.min_bid),
+                        auction.money(auction.options.min_bid),
                     ),
                     Some(Bid { amount, bidder, .. }) => format!(
                         "The {} still has {} remaining. The leader is \
@@ -332,7 +1131,7 @@ impl Bot {
                         auction.describe(),
                         time,
                         bidder,
-                        money!(amount),
+                        auction.money(*amount),
                     ),
                 }))
             }
@@ -347,7 +1146,7 @@ impl Bot {
 
                     Some(Reply(match &auction.prize {
                         Some(s) => format!("The current Auction is for {}.", s),
-                        None => format!("The Auction prize has been unset."),
+                        None => String::from("The Auction prize has been unset."),
                     }))
                 }
                 "start" => {
@@ -361,60 +1160,282 @@ impl Bot {
                         )))
                     } else {
                         let channel = msg.channel().trim_start_matches('#');
-                        let mut dur = self.config.duration(channel);
-                        let mut hlm = self.config.helmet(channel);
-                        let mut max = self.config.max_raise(channel);
-                        let mut min = self.config.min_bid(channel);
-                        let mut vrb = self.config.verb(channel);
-                        let mut tok = args.iter();
-                        let mut prz = None;
-
-                        while let Some(flag) = tok.next() {
-                            match *flag {
-                                "-d" | "-t" | "--time"
-                                => if let Some(val) = tok.next() {
-                                    if let Ok(vl) = val.parse() {
-                                        dur = Duration::from_secs(vl);
-                                    }
-                                }
-                                "-h" | "--helm" | "--helmet"
-                                => if let Some(val) = tok.next() {
-                                    if let Ok(vl) = val.parse() {
-                                        hlm = Duration::from_secs(vl);
-                                    }
-                                }
-                                "-r" | "--raise" | "--limit"
-                                => if let Some(val) = tok.next() {
-                                    if let Ok(vl) = val.parse() {
-                                        max = vl;
-                                    }
-                                }
-                                "-m" | "--min"
-                                => if let Some(val) = tok.next() {
-                                    if let Ok(vl) = val.parse() {
-                                        min = vl;
-                                    }
-                                }
-                                "-v" | "--verb"
-                                => if let Some(val) = tok.next() {
-                                    vrb = val;
-                                }
-                                "--prize" => {
-                                    prz = tok.next();
+                        let setup: AuctionSetup = match self.auction_setup(channel, args) {
+                            Ok(setup) => setup,
+                            Err(text) => return Some(Reply(text)),
+                        };
+
+                        info!("Auction in #{} started by {}.", channel, author);
+                        let (auction, verb) = setup.begin();
+                        let new: &mut Auction = lock.insert(auction);
+
+                        Some(Message(new.explain(self.config.prefix(), &verb)))
+                    }
+                }
+                "schedule" => {
+                    let channel = msg.channel().trim_start_matches('#');
+                    let (delay, rest) = match parse_when(args) {
+                        Ok(when) => when,
+                        Err(WhenError::Invalid) => return Some(Reply(format!(
+                            "Specify when the Auction should start, like \
+                            '{0}auction schedule 20:30' or '{0}auction schedule \
+                            in 15m'.",
+                            self.config.prefix(),
+                        ))),
+                        #[cfg(feature = "chrono")]
+                        Err(WhenError::Skipped) => return Some(Reply(format!(
+                            "The clocks skip over {} because of daylight saving \
+                            time, so the Auction cannot start then. Choose another \
+                            time, or give a delay instead.",
+                            args[0],
+                        ))),
+                        #[cfg(feature = "chrono")]
+                        Err(WhenError::Repeated) => return Some(Reply(format!(
+                            "The clocks pass {} twice because of daylight saving \
+                            time, so it is not clear when the Auction should \
+                            start. Choose another time, or give a delay instead.",
+                            args[0],
+                        ))),
+                    };
+                    let setup: AuctionSetup = match self.auction_setup(channel, rest) {
+                        Ok(setup) => setup,
+                        Err(text) => return Some(Reply(text)),
+                    };
+
+                    info!(
+                        "Auction in #{} scheduled by {}, to start in {}s.",
+                        channel, author, delay.as_secs(),
+                    );
+                    let text: String = format!(
+                        "SCHEDULED: The {} will begin in {}.",
+                        setup.describe(), format_duration(delay),
+                    );
+                    let new = Scheduled::new(
+                        setup,
+                        author.to_owned(),
+                        self.clock.now() + delay,
+                        &self.config.schedule_announce(channel),
+                    );
+
+                    let mut queue = self.schedule.lock();
+                    let idx: usize = queue.partition_point(|s| s.start <= new.start);
+                    queue.insert(idx, new);
+
+                    Some(Message(text))
+                }
+                "scheduled" => {
+                    let queue = self.schedule.lock();
+
+                    Some(Reply(match queue.len() {
+                        0 => String::from("No Auctions are scheduled."),
+                        _ => queue.iter().enumerate()
+                            .map(|(idx, next)| format!(
+                                "{}. The {}, in {}, scheduled by {}.",
+                                idx + 1,
+                                next.setup.describe(),
+                                format_duration(Duration::from_secs(next.until().as_secs())),
+                                next.by,
+                            ))
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                    }))
+                }
+                "unschedule" => {
+                    let mut queue = self.schedule.lock();
+
+                    match args {
+                        ["all", ..] => {
+                            let count: usize = queue.len();
+                            queue.clear();
+
+                            Some(Reply(format!("{} scheduled Auction(s) cancelled.", count)))
+                        }
+                        _ => {
+                            let idx: usize = match args.first() {
+                                Some(num) => match num.parse::<usize>() {
+                                    Ok(n) if 1 <= n && n <= queue.len() => n - 1,
+                                    _ => return Some(Reply(format!(
+                                        "There is no scheduled Auction with that \
+                                        number. See '{}auction scheduled'.",
+                                        self.config.prefix(),
+                                    ))),
                                 }
-                                _ => {}
-                            }
+                                None if queue.is_empty() => return Some(Reply(
+                                    String::from("No Auctions are scheduled."),
+                                )),
+                                None => 0,
+                            };
+
+                            let removed: Scheduled = queue.remove(idx);
+                            info!("Scheduled Auction cancelled by {}.", author);
+
+                            Some(Message(format!(
+                                "CANCELLED: The {} scheduled to begin in {} will \
+                                not take place.",
+                                removed.setup.describe(),
+                                format_duration(Duration::from_secs(removed.until().as_secs())),
+                            )))
                         }
+                    }
+                }
+                "pause" => {
+                    let mut lock = self.auction.lock();
+                    let auction: &mut Auction = lock.as_mut()?;
 
-                        info!("Auction in #{} started by {}.", channel, author);
-                        let new: &mut Auction = lock.insert(Auction::new(
-                            dur, hlm, max, min, prz.map(|s| String::from(
-                                unquote(s),
-                            )),
-                        ));
+                    Some(match auction.pause(PauseReason::Operator, Some(author.to_owned())) {
+                        true => Message(format!(
+                            "PAUSED: The {} has been paused, with {} \
+                            remaining. Bids will not be accepted until it \
+                            resumes.",
+                            auction.describe(),
+                            format_duration(auction.remaining().unwrap_or_default()),
+                        )),
+                        false => Reply(format!(
+                            "The {} is already paused.",
+                            auction.describe(),
+                        )),
+                    })
+                }
+                "resume" => {
+                    let mut lock = self.auction.lock();
+                    let auction: &mut Auction = lock.as_mut()?;
+
+                    Some(match auction.resume() {
+                        Some(..) => Message(format!(
+                            "RESUMED: The {} has resumed, with {} remaining. \
+                            Bids are being accepted again.",
+                            auction.describe(),
+                            format_duration(auction.remaining().unwrap_or_default()),
+                        )),
+                        None => Reply(format!(
+                            "The {} is not paused.",
+                            auction.describe(),
+                        )),
+                    })
+                }
+                "extend" | "shorten" | "time" => {
+                    let mut lock = self.auction.lock();
+                    let auction: &mut Auction = lock.as_mut()?;
+
+                    let text: &str = match to_end_unquoted(line, args) {
+                        Some(text) => text,
+                        None => return Some(Reply(format!(
+                            "Specify a length of time, like '{}auction {} 2m'.",
+                            self.config.prefix(), subcom,
+                        ))),
+                    };
+
+                    let time: Duration = match parse_duration(text) {
+                        Ok(time) => time,
+                        Err(..) => return Some(Reply(format!(
+                            "{:?} is not a length of time. Try something like \
+                            '2m' or '30s'.",
+                            text,
+                        ))),
+                    };
+
+                    let result: Option<Duration> = match *subcom {
+                        "extend" => auction.extend(author, time),
+                        "shorten" => auction.shorten(author, time),
+                        _ => auction.set_remaining(author, time),
+                    };
+
+                    Some(match result {
+                        Some(left) => Message(format!(
+                            "TIME: The {} now has {} remaining.",
+                            auction.describe(),
+                            format_duration(Duration::from_secs(left.as_secs())),
+                        )),
+                        None if auction.options.mode == AuctionMode::Dutch => {
+                            Reply(format!(
+                                "The length of a {} is set by its prices, and \
+                                cannot be changed.",
+                                auction.describe(),
+                            ))
+                        }
+                        None => Reply(format!(
+                            "The {} has already ended.",
+                            auction.describe(),
+                        )),
+                    })
+                }
+                "recover" => {
+                    if self.auction.lock().is_some() {
+                        return Some(Reply(String::from(
+                            "An Auction is already running.",
+                        )));
+                    }
+
+                    let id: String = self.config.auction_id(&self.channel);
+                    let snapshot: Snapshot = match Snapshot::load(&id) {
+                        Ok(Some(snapshot)) => snapshot,
+                        Ok(None) => return Some(Reply(String::from(
+                            "There is no interrupted Auction to recover.",
+                        ))),
+                        Err(e) => {
+                            err!("Failed to read Snapshot: {}", e);
+                            return Some(Reply(String::from(
+                                "The interrupted Auction could not be read. See \
+                                the log for details.",
+                            )));
+                        }
+                    };
 
-                        Some(Message(new.explain(self.config.prefix(), &vrb)))
+                    Some(match self.recover(snapshot) {
+                        Ok(text) => Message(text),
+                        Err(text) => Reply(text),
+                    })
+                }
+                "reassign" => {
+                    if self.auction.lock().is_some() {
+                        return Some(Reply(String::from(
+                            "An Auction is still running; Only a finished \
+                            Auction can be reassigned.",
+                        )));
                     }
+
+                    let mut lock = self.finished.lock();
+                    let auction: &mut Auction = match lock.as_mut() {
+                        Some(auction) => auction,
+                        None => return Some(Reply(String::from(
+                            "There is no finished Auction to reassign.",
+                        ))),
+                    };
+
+                    let (old, new) = match auction.reassign() {
+                        Some(result) => result,
+                        None => return Some(Reply(format!(
+                            "The last {} has no winner to reassign.",
+                            auction.describe(),
+                        ))),
+                    };
+
+                    info!("Auction in #{} reassigned by {}.", self.channel, author);
+
+                    //  The Summary file is overwritten, and a new row is added
+                    //      to the CSV file.
+                    if self.config.summary(&self.channel) {
+                        #[cfg(feature = "csv")]
+                        save_summary(
+                            auction, &self.channel,
+                            self.config.file_csv().map(|p| p.as_path()),
+                        );
+                        #[cfg(not(feature = "csv"))]
+                        save_summary(auction, &self.channel);
+                    }
+
+                    Some(Message(match new {
+                        Some(Winner { name, amount, .. }) => format!(
+                            "REASSIGNED: @{} has been disqualified. The {} \
+                            now goes to @{}, with a bid of {}.",
+                            old, auction.describe(), name, auction.money(amount),
+                        ),
+                        None => format!(
+                            "REASSIGNED: @{} has been disqualified. There are \
+                            no other eligible bids, so the {} has no winner.",
+                            old, auction.describe(),
+                        ),
+                    }))
                 }
                 "stop" => Some(Reply(match self.auction.lock().take() {
                     Some(..) => String::from("Auction stopped."),
@@ -422,55 +1443,216 @@ impl Bot {
                 })),
                 _ => None,
             }
-            ["bid", value, ..] => match unquote(value)
-                .trim_start_matches(money!(""))
-                .parse::<usize>()
-            {
-                Ok(bid) => Some(match self.auction.lock()
-                    .as_mut()?
-                    .bid(&author, bid)
-                {
-                    BidResult::Ok { first } => Message(format!(
-                        "{} BID: @{} has bid {}.",
-                        if first { "FIRST" } else { "NEW" },
-                        author,
-                        money!(bid),
-                    )),
-                    BidResult::RepeatBidder(bid) => Reply(format!(
-                        "You are already the top bidder at {}.",
-                        money!(bid),
-                    )),
-                    BidResult::AboveMaximum(max) => Reply(format!(
-                        "You can only raise by a maximum of {}.",
-                        money!(max),
-                    )),
-                    BidResult::BelowMinimum(min) => Reply(format!(
-                        "The minimum bid is {}.",
-                        money!(min),
-                    )),
-                    BidResult::DoesNotRaise(cur) => Reply(format!(
-                        "The current bid is {}.",
-                        money!(cur),
-                    )),
-                }),
-                Err(..) if self.auction.lock().is_some() => Some(Reply(format!(
-                    "A bid must be a positive whole number of {}.",
-                    money!(),
-                ))),
-                _ => None,
+            ["bid", value, ..] => {
+                //  Attempts only count toward the cooldown during an Auction.
+                self.auction_mode()?;
+
+                let throttled: bool = self.should_throttle(msg.name());
+                let cap: Option<usize> = self.config.max_rejection_replies(&self.channel);
+                let mut lock = self.auction.lock();
+                let auction: &mut Auction = lock.as_mut()?;
+                auction.note_login(author, msg.name());
+                let eligible: bool = auction.options.eligible.admits(&badges(msg));
+                let bits: u64 = match auction.options.bits {
+                    BitsMode::Off => 0,
+                    _ => msg.bits().unwrap_or(0),
+                };
+
+                if throttled {
+                    auction.throttled += 1;
+                    auction.bits_refused += bits;
+                    return None;
+                }
+
+                //  In the Bid mode, a cheer is the bid, and the rest of the
+                //      message is just the cheer text.
+                let amount = match auction.options.bits {
+                    BitsMode::Bid if 0 < bits => Ok(auction.bits_value(bits)),
+                    _ => BidAmount::parse(unquote(value), &auction.options.currency)
+                        .map(|amount| auction.resolve(amount)),
+                };
+
+                match amount {
+                    Ok(bid) => {
+                        let result: BidResult = match eligible {
+                            true if 0 < bits => {
+                                auction.bid_cheered(author, bid, &self.channel, bits)
+                            }
+                            true => auction.bid(author, bid, &self.channel),
+                            false => {
+                                auction.bits_refused += bits;
+                                BidResult::Ineligible
+                            }
+                        };
+
+                        match result.is_rejection() && !reply_to_rejection(auction, cap) {
+                            true => None,
+                            false => Some(bid_response(auction, author, bid, result)),
+                        }
+                    }
+                    Err(..) => {
+                        auction.bits_refused += bits;
+                        reply_to_rejection(auction, cap)
+                            .then(|| Reply(invalid_amount(&auction.options.currency)))
+                    }
+                }
+            }
+            ["maxbid", value, ..] => {
+                //  Attempts only count toward the cooldown during an Auction.
+                self.auction_mode()?;
+
+                let throttled: bool = self.should_throttle(msg.name());
+                let cap: Option<usize> = self.config.max_rejection_replies(&self.channel);
+                let mut lock = self.auction.lock();
+                let auction: &mut Auction = lock.as_mut()?;
+                auction.note_login(author, msg.name());
+                let eligible: bool = auction.options.eligible.admits(&badges(msg));
+
+                if throttled {
+                    auction.throttled += 1;
+                    return None;
+                }
+
+                let amount = BidAmount::parse(unquote(value), &auction.options.currency)
+                    .map(|amount| auction.resolve(amount));
+
+                match amount {
+                    Ok(max) => {
+                        let result: ProxyResult = match eligible {
+                            true => auction.set_proxy(author, max, &self.channel),
+                            false => ProxyResult::Ineligible,
+                        };
+
+                        match result.is_rejection() && !reply_to_rejection(auction, cap) {
+                            true => None,
+                            false => Some(proxy_response(auction, max, result)),
+                        }
+                    }
+                    Err(..) => reply_to_rejection(auction, cap)
+                        .then(|| Reply(invalid_amount(&auction.options.currency))),
+                }
+            }
+            ["buy", ..] => {
+                if self.auction_mode()? != AuctionMode::Dutch {
+                    return None;
+                }
+
+                let throttled: bool = self.should_throttle(msg.name());
+                let cap: Option<usize> = self.config.max_rejection_replies(&self.channel);
+                let mut lock = self.auction.lock();
+                let auction: &mut Auction = lock.as_mut()?;
+                auction.note_login(author, msg.name());
+
+                if throttled {
+                    auction.throttled += 1;
+                    return None;
+                }
+
+                let eligible: bool = auction.options.eligible.admits(&badges(msg));
+                let price: Money = auction.price().unwrap_or_default();
+                let result: BidResult = match eligible {
+                    true => auction.buy(author, &self.channel),
+                    false => BidResult::Ineligible,
+                };
+
+                match result.is_rejection() && !reply_to_rejection(auction, cap) {
+                    true => None,
+                    false => Some(bid_response(auction, author, price, result)),
+                }
+            }
+            ["balance", name, ..] if usr_op => {
+                let ledger = self.ledger()?;
+                let currency: Currency = self.config.currency(&self.channel);
+                let name: &str = name.trim_start_matches('@');
+                let balance: Money = ledger.lock().balance(name);
+
+                Some(Reply(format!(
+                    "@{} has a balance of {}.",
+                    name, currency.format(balance),
+                )))
+            }
+            ["balance", ..] => {
+                let ledger = self.ledger()?;
+                let currency: Currency = self.config.currency(&self.channel);
+                let balance: Money = ledger.lock().balance(msg.name());
+
+                Some(Reply(format!("Your balance is {}.", currency.format(balance))))
+            }
+            ["wallet", subcom @ ("grant" | "revoke"), name, value, ..] if usr_op => {
+                let ledger = match self.ledger() {
+                    Some(ledger) => ledger,
+                    None => return Some(Reply(String::from(
+                        "Wallets are not enabled in this channel.",
+                    ))),
+                };
+                let currency: Currency = self.config.currency(&self.channel);
+                let name: &str = name.trim_start_matches('@');
+                let amount: Money = match currency.parse(unquote(value)) {
+                    Ok(amount) => amount,
+                    Err(e) => return Some(Reply(format!("Cannot use that amount: {}.", e))),
+                };
+
+                let balance: Money = match *subcom {
+                    "grant" => ledger.lock().grant(name, amount),
+                    _ => ledger.lock().revoke(name, amount),
+                };
+
+                info!(
+                    "Wallet of {} changed by {} ({} {}).",
+                    name, author, subcom, currency.format(amount),
+                );
+                Some(Reply(format!(
+                    "The balance of @{} is now {}.",
+                    name, currency.format(balance),
+                )))
+            }
+            ["wallet", ..] if usr_op => Some(Reply(format!(
+                "Usage: '{0}wallet grant <user> <amount>' or \
+                '{0}wallet revoke <user> <amount>'.",
+                self.config.prefix(),
+            ))),
+            ["config", "presets", ..] if usr_op => {
+                let channel = msg.channel().trim_start_matches('#');
+                let currency: Currency = self.config.currency(channel);
+                let presets = self.config.presets(channel);
+
+                Some(Reply(match presets.is_empty() {
+                    true => String::from("No presets are configured."),
+                    false => format!(
+                        "Presets: {}. Use one with '{}auction start --preset \
+                        <name>'.",
+                        presets.iter()
+                            .map(|(name, preset)| format!(
+                                "{} ({})", name, preset.describe(&currency),
+                            ))
+                            .collect::<Vec<_>>()
+                            .join("; "),
+                        self.config.prefix(),
+                    ),
+                }))
             }
             ["config", ..] if usr_op => {
                 let channel = msg.channel().trim_start_matches('#');
+                let currency: Currency = self.config.currency(channel);
 
                 Some(Reply(format!(
                     "Auction length is {dur} seconds. \
                     Helmet value is {hlm} seconds. \
                     Minimum bid is {min}. \
-                    Maximum raise is {max}.",
+                    Minimum raise is {inc}. \
+                    Maximum raise is {max}. \
+                    Maximum first bid is {opn}. \
+                    {elg}",
                     dur = self.config.duration(channel).as_secs(),
+                    elg = self.config.eligible(channel).explain(),
                     hlm = self.config.helmet(channel).as_secs(),
-                    max = money!(self.config.max_raise(channel)),
-                    min = money!(self.config.min_bid(channel)),
+                    inc = self.config.min_raise(channel).format(&currency),
+                    max = currency.format(self.config.max_raise(channel)),
+                    min = currency.format(self.config.min_bid(channel)),
+                    opn = match self.config.auction_options(channel).opening_limit() {
+                        Some(limit) => currency.format(limit),
+                        None => String::from("unlimited"),
+                    },
                 )))
             }
             #[cfg(debug_assertions)]
@@ -501,11 +1683,25 @@ impl Bot {
                 chat!("({}) {}: {:?}", msg.channel(), msg.name(), msg.data());
 
                 if let Some(reply) = self.handle_command(&msg, line, &words).await {
+                    //  Everything posted publicly, other than an echo, is news
+                    //      about the Auction, which every sharing Channel needs.
+                    let relay: Option<String> = match &reply {
+                        Response::Message(text)
+                        if self.group.is_shared() && words.first() != Some(&"echo") => {
+                            Some(text.clone())
+                        }
+                        _ => None,
+                    };
+
                     if let Some(client) = &mut self.client {
                         if let Err(err) = client.respond(&msg, reply).await {
                             warn!("Failed to send message: {}", err);
                         }
                     }
+
+                    if let Some(text) = relay {
+                        self.group.relay(&self.channel, &text).await;
+                    }
                 }
             }
 
@@ -528,12 +1724,200 @@ impl Bot {
             // RoomState(_) => {}
             // UserNotice(_) => {}
             // UserState(_) => {}
-            // Whisper(_) => {}
+            Whisper(msg) => self.handle_whisper(&msg).await,
 
             _ => {}
         }
     }
 
+    async fn handle_whisper(&mut self, msg: &Whisper<'_>) {
+        if self.config.is_blacklisted(msg.name(), &self.channel) {
+            return;
+        }
+
+        if let Some((_, words)) = self.find_command(msg.data()) {
+            let author: &str = msg.display_name().unwrap_or_else(|| msg.name());
+
+            //  Every Channel receives every Whisper, so a bidder may name
+            //      the Channel the Whisper is meant for.
+            let named: Option<&str> = match words.as_slice() {
+                ["bid" | "maxbid", _, channel, ..] | ["balance", channel, ..] => {
+                    Some(channel.trim_start_matches('#'))
+                }
+                ["bid" | "maxbid", _] | ["balance"] => None,
+                _ => return,
+            };
+
+            let channel: String = match named {
+                Some(channel) if self.group.includes(channel) => {
+                    channel.to_ascii_lowercase()
+                }
+                Some(..) => return,
+                //  A Whisper that names no Channel is handled by exactly one
+                //      group, or by nobody if it is not clear which one.
+                None => match Addressee::find(words[0] != "balance") {
+                    Addressee::Group(group) if Arc::ptr_eq(&group, &self.group) => {
+                        self.channel.clone()
+                    }
+                    Addressee::Unclear if answers_whispers(&self.channel) => {
+                        let text: String = format!(
+                            "I am in more than one channel, so please name the \
+                            channel this is meant for, like '{}{} channel'.",
+                            self.config.prefix(),
+                            words.join(" "),
+                        );
+
+                        if let Some(client) = &mut self.client {
+                            if let Err(err) = client.whisper(msg.name(), text).await {
+                                warn!("Failed to send whisper: {}", err);
+                            }
+                        }
+
+                        return;
+                    }
+                    _ => return,
+                }
+            };
+
+            let mut announce: Option<String> = None;
+            let reply: String = match words.as_slice() {
+                //  Channels sharing an Auction also share their Whispers, so
+                //      only one of them may handle each.
+                _ if !self.group.leads(&self.channel) => return,
+                ["bid", value, ..] => {
+                    if self.auction_mode() != Some(AuctionMode::Sealed) {
+                        return;
+                    }
+
+                    let throttled: bool = self.should_throttle(msg.name());
+                    let cap: Option<usize> = self.config.max_rejection_replies(&self.channel);
+                    let mut lock = self.auction.lock();
+                    let auction: &mut Auction = match lock.as_mut() {
+                        Some(auct) => auct,
+                        None => return,
+                    };
+
+                    auction.note_login(author, msg.name());
+
+                    chat!("(whisper) {}: {:?}", msg.name(), msg.data());
+
+                    if throttled {
+                        auction.throttled += 1;
+                        return;
+                    }
+
+                    let amount = BidAmount::parse(unquote(value), &auction.options.currency)
+                        .map(|amount| auction.resolve(amount));
+                    let (rejected, text): (bool, String) = match amount {
+                        //  Whispers do not carry Channel badges.
+                        _ if !auction.options.eligible.is_open() => {
+                            (true, restricted_whisper(auction))
+                        }
+                        //  Only the bidder sees a Whisper, so it may confirm
+                        //      the amount that a shorthand resolved to.
+                        Ok(bid) => match auction.bid(author, bid, &channel) {
+                            BidResult::Sealed { revised: false } => (false, format!(
+                                "Your sealed bid of {} has been recorded.",
+                                auction.money(bid),
+                            )),
+                            BidResult::Sealed { revised: true } => (false, format!(
+                                "Your sealed bid has been replaced with {}.",
+                                auction.money(bid),
+                            )),
+                            result => {
+                                let rejected: bool = result.is_rejection();
+                                (rejected, bid_response(auction, author, bid, result).into_text())
+                            }
+                        }
+                        Err(..) => (true, invalid_amount(&auction.options.currency)),
+                    };
+
+                    if rejected && !reply_to_rejection(auction, cap) {
+                        return;
+                    }
+
+                    text
+                }
+                ["maxbid", value, ..] => {
+                    if self.auction_mode() != Some(AuctionMode::Open) {
+                        return;
+                    }
+
+                    let throttled: bool = self.should_throttle(msg.name());
+                    let cap: Option<usize> = self.config.max_rejection_replies(&self.channel);
+                    let mut lock = self.auction.lock();
+                    let auction: &mut Auction = match lock.as_mut() {
+                        Some(auct) => auct,
+                        None => return,
+                    };
+
+                    auction.note_login(author, msg.name());
+
+                    chat!("(whisper) {}: {:?}", msg.name(), msg.data());
+
+                    if throttled {
+                        auction.throttled += 1;
+                        return;
+                    }
+
+                    let amount = BidAmount::parse(unquote(value), &auction.options.currency)
+                        .map(|amount| auction.resolve(amount));
+                    let (rejected, text): (bool, String) = match amount {
+                        //  Whispers do not carry Channel badges.
+                        _ if !auction.options.eligible.is_open() => {
+                            (true, restricted_whisper(auction))
+                        }
+                        Ok(max) => match auction.set_proxy(author, max, &channel) {
+                            ProxyResult::Set { proxied } => {
+                                if !proxied.is_empty() {
+                                    announce = Some(announce_bids(auction, &proxied));
+                                }
+
+                                (false, format!(
+                                    "Your maximum bid of {} has been registered.",
+                                    auction.money(max),
+                                ))
+                            }
+                            result => (true, proxy_response(auction, max, result).into_text()),
+                        }
+                        Err(..) => (true, invalid_amount(&auction.options.currency)),
+                    };
+
+                    if rejected && !reply_to_rejection(auction, cap) {
+                        return;
+                    }
+
+                    text
+                }
+                ["balance", ..] => {
+                    let ledger = match self.ledger() {
+                        Some(ledger) => ledger,
+                        None => return,
+                    };
+                    let currency: Currency = self.config.currency(&self.channel);
+                    let balance: Money = ledger.lock().balance(msg.name());
+
+                    format!("Your balance is {}.", currency.format(balance))
+                }
+                _ => return,
+            };
+
+            if let Some(client) = &mut self.client {
+                if let Err(err) = client.whisper(msg.name(), reply).await {
+                    warn!("Failed to send whisper: {}", err);
+                }
+
+                if let Some(text) = announce {
+                    if let Err(err) = client.send(&text).await {
+                        warn!("Failed to send message: {}", err);
+                    }
+
+                    self.group.relay(&self.channel, &text).await;
+                }
+            }
+        }
+    }
+
     pub fn find_command<'s>(&self, text: &'s str) -> Option<(&'s str, Vec<&'s str>)> {
         match text.strip_prefix(self.config.prefix()) {
             Some(line) => if self.config.parse_commands() {
@@ -1,16 +1,44 @@
-use std::time::{Duration, Instant};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 #[cfg(feature = "chrono")]
 use chrono::{DateTime, SubsecRound, Utc};
-use crate::saving::AuctionFinished;
+use parking_lot::Mutex;
+use crate::{
+    currency::{Currency, Money, ParseMoneyError},
+    saving::{AuctionFinished, Ledger},
+};
+use super::{clock::{Clock, SystemClock}, util::BidAmount};
 
 
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "summaries_pascal", serde(rename_all = "PascalCase"))]
 pub struct Bid {
-    pub amount: usize,
+    pub amount: Money,
     pub bidder: String,
     #[cfg(feature = "chrono")]
     pub time: DateTime<Utc>,
+    /// Whether this bid was placed automatically on behalf of the bidder.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub proxy: bool,
+    /// The Channel in which the bid was placed, if the Auction was shared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// The number of Bits cheered in the message that placed the bid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bits: Option<u64>,
+}
+
+
+/// A maximum bid, up to which the bot will outbid others automatically.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Proxy {
+    pub bidder: String,
+    pub ceiling: Money,
 }
 
 
@@ -18,66 +46,913 @@ pub struct Bid {
 #[cfg_attr(feature = "summaries_pascal", serde(rename_all = "PascalCase"))]
 pub struct Winner {
     pub name: String,
-    pub amount: usize,
+    /// The Channel of the winning bid, if the Auction was shared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    pub amount: Money,
+    pub price_paid: Money,
     pub bid_count: usize,
+    /// The total number of Bits cheered with the bids of the winner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bits: Option<u64>,
+}
+
+
+#[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "summaries_pascal", serde(rename_all = "PascalCase"))]
+pub struct PricePoint {
+    pub price: Money,
+    #[cfg(feature = "chrono")]
+    pub time: DateTime<Utc>,
 }
 
 
 pub enum BidResult {
-    Ok { first: bool },
-    RepeatBidder(usize),
-    AboveMaximum(usize),
-    BelowMinimum(usize),
-    DoesNotRaise(usize),
+    Ok { first: bool, proxied: Vec<(String, Money)> },
+    Sealed { revised: bool },
+    Bought(Money),
+    BuyNow(Money),
+    Closed,
+    Paused,
+    Ineligible,
+    BelowPrice(Money),
+    RepeatBidder(Money),
+    AboveMaximum(Money),
+    AboveOpening(Money),
+    /// The bid is more than the balance of the bidder, which is given.
+    AboveBalance(Money),
+    BelowIncrement(Money),
+    BelowMinimum(Money),
+    DoesNotRaise(Money),
+}
+
+
+impl BidResult {
+    /// Determine whether the bid was refused.
+    pub fn is_rejection(&self) -> bool {
+        !matches!(
+            self,
+            Self::Ok { .. } | Self::Sealed { .. } | Self::Bought(..) | Self::BuyNow(..),
+        )
+    }
+}
+
+
+pub enum ProxyResult {
+    Set { proxied: Vec<(String, Money)> },
+    Closed,
+    Paused,
+    Ineligible,
+    TooLow(Money),
+    /// The maximum is more than the balance of the bidder, which is given.
+    AboveBalance(Money),
+    Unavailable,
+}
+
+impl ProxyResult {
+    /// Determine whether the maximum bid was refused.
+    pub fn is_rejection(&self) -> bool {
+        !matches!(self, Self::Set { .. })
+    }
+}
+
+
+/// The manner in which bids are collected and revealed.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuctionMode {
+    /// Every bid is announced in chat, and must exceed the previous one.
+    #[default]
+    Open,
+    /// Bids are not revealed until the Auction ends. Every bidder may submit
+    ///     one bid, which may be replaced any number of times.
+    Sealed,
+    /// The price starts high and drops on a schedule. The first person to
+    ///     accept the current price wins immediately.
+    Dutch,
+}
+
+impl FromStr for AuctionMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "open" => Ok(Self::Open),
+            "sealed" | "secret" => Ok(Self::Sealed),
+            "dutch" | "descending" => Ok(Self::Dutch),
+            _ => Err(()),
+        }
+    }
+}
+
+
+/// The way in which a Helmet changes the timer, when a bid arrives with less
+///     time remaining than the Helmet value.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HelmetPolicy {
+    /// Add time to the timer: The Helmet value, or the Helmet extension if
+    ///     one is set.
+    #[default]
+    Extend,
+    /// Set the time remaining to exactly the Helmet value.
+    Reset,
+}
+
+impl FromStr for HelmetPolicy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "extend" | "add" => Ok(Self::Extend),
+            "reset" => Ok(Self::Reset),
+            _ => Err(()),
+        }
+    }
+}
+
+
+/// The way in which Bits cheered in the same message as a bid are treated.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BitsMode {
+    /// Bits are not treated differently from any other cheer.
+    #[default]
+    Off,
+    /// The value of the Bits is the amount of the bid, whatever else the
+    ///     message says.
+    Bid,
+    /// The Bits are payment toward the bid, which is given as usual.
+    Deposit,
+}
+
+impl BitsMode {
+    pub fn is_off(&self) -> bool {
+        *self == Self::Off
+    }
+}
+
+impl FromStr for BitsMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "off" | "none" => Ok(Self::Off),
+            "bid" | "bids" => Ok(Self::Bid),
+            "deposit" | "payment" => Ok(Self::Deposit),
+            _ => Err(()),
+        }
+    }
+}
+
+
+/// The rule that determines how much the winner of an Auction must pay.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Settlement {
+    /// The winner pays the amount of their own bid.
+    #[default]
+    First,
+    /// The winner pays the amount of the second-highest bid, or the minimum
+    ///     bid if nobody else placed a bid. Also known as a Vickrey Auction.
+    Second,
+}
+
+impl FromStr for Settlement {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "first" | "first-price" => Ok(Self::First),
+            "second" | "second-price" | "vickrey" => Ok(Self::Second),
+            _ => Err(()),
+        }
+    }
+}
+
+
+/// The smallest amount by which a new bid must exceed the current bid. May be
+///     specified as a fixed amount, like `5`, or as a percentage of the current
+///     bid, like `"10%"`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "IncrementRaw", into = "IncrementRaw")]
+pub enum Increment {
+    Absolute(Money),
+    Percent(u64),
 }
 
+impl Increment {
+    /// Determine the smallest raise allowed over the given bid. This is never
+    ///     less than the smallest unit of the currency, and a percentage is
+    ///     rounded up to a multiple of it.
+    pub fn over(&self, current: Money, unit: Money) -> Money {
+        match *self {
+            Self::Absolute(amount) => amount,
+            Self::Percent(pct) => current.percent(pct).round_up(unit),
+        }.max(unit)
+    }
+
+    /// Write this increment for chat, in the given currency.
+    pub fn format(&self, currency: &Currency) -> String {
+        match *self {
+            Self::Absolute(amount) => currency.format(amount),
+            Self::Percent(pct) => format!("{}%", pct),
+        }
+    }
+}
+
+impl Default for Increment {
+    fn default() -> Self { Self::Absolute(Money::whole(1)) }
+}
+
+impl FromStr for Increment {
+    type Err = ParseMoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: &str = s.trim();
+
+        match s.strip_suffix('%') {
+            Some(pct) => Ok(Self::Percent(
+                pct.trim_end().parse().map_err(|_| ParseMoneyError::Invalid)?
+            )),
+            //  Allow a leading currency symbol, whatever it may be.
+            None => Ok(Self::Absolute(
+                s.trim_start_matches(|c: char| !c.is_alphanumeric() && c != '-')
+                    .parse()?
+            )),
+        }
+    }
+}
+
+
+/// Representation of an [`Increment`] in a Configuration file.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum IncrementRaw {
+    Amount(Money),
+    Text(String),
+}
+
+impl TryFrom<IncrementRaw> for Increment {
+    type Error = String;
+
+    fn try_from(raw: IncrementRaw) -> Result<Self, Self::Error> {
+        match raw {
+            IncrementRaw::Amount(amount) => Ok(Self::Absolute(amount)),
+            IncrementRaw::Text(text) => text.parse()
+                .map_err(|_| format!("invalid raise increment: {:?}", text)),
+        }
+    }
+}
+
+impl From<Increment> for IncrementRaw {
+    fn from(inc: Increment) -> Self {
+        match inc {
+            Increment::Absolute(amount) => Self::Amount(amount),
+            Increment::Percent(pct) => Self::Text(format!("{}%", pct)),
+        }
+    }
+}
+
+
+/// Rules about which Twitch badges a user must have, or must not have, in
+///     order to bid. Written as a comma-separated list of badge names, like
+///     `"subs,vip"`, where a name with a leading `!` excludes that badge.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Eligibility {
+    /// Badges of which a bidder must have at least one. If this is empty,
+    ///     anyone may bid.
+    pub allow: Vec<String>,
+    /// Badges which prevent a user from bidding.
+    pub deny: Vec<String>,
+}
+
+impl Eligibility {
+    /// Determine whether these rules allow anyone at all to bid.
+    pub fn is_open(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty()
+    }
+
+    /// Determine whether a user with the given badges may bid.
+    pub fn admits(&self, badges: &[&str]) -> bool {
+        let has = |name: &String| badges.iter().any(|badge| {
+            badge.eq_ignore_ascii_case(name)
+                //  Founders are the earliest subscribers of a channel, and have
+                //      a badge of their own in place of the subscriber badge.
+                || (name == "subscriber" && badge.eq_ignore_ascii_case("founder"))
+        });
+
+        !self.deny.iter().any(has)
+            && (self.allow.is_empty() || self.allow.iter().any(has))
+    }
+
+    /// Describe these rules for chat, as a full sentence.
+    pub fn explain(&self) -> String {
+        fn label(badge: &str) -> String {
+            match badge {
+                "subscriber" => String::from("subscribers"),
+                "vip" => String::from("VIPs"),
+                "moderator" => String::from("moderators"),
+                "broadcaster" => String::from("the broadcaster"),
+                other => format!("users with the '{}' badge", other),
+            }
+        }
+
+        fn list(badges: &[String]) -> String {
+            let labels: Vec<String> = badges.iter().map(|b| label(b)).collect();
+
+            match labels.as_slice() {
+                [] => String::new(),
+                [one] => one.clone(),
+                [init @ .., last] => format!("{} or {}", init.join(", "), last),
+            }
+        }
+
+        match (self.allow.is_empty(), self.deny.is_empty()) {
+            (true, true) => String::from("Anyone may bid."),
+            (false, true) => format!("Only {} may bid.", list(&self.allow)),
+            (true, false) => format!("Bids are not accepted from {}.", list(&self.deny)),
+            (false, false) => format!(
+                "Only {} may bid, and bids are not accepted from {}.",
+                list(&self.allow), list(&self.deny),
+            ),
+        }
+    }
+}
 
+impl Display for Eligibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let allow = self.allow.iter().map(String::from);
+        let deny = self.deny.iter().map(|badge| format!("!{}", badge));
+
+        write!(f, "{}", allow.chain(deny).collect::<Vec<_>>().join(","))
+    }
+}
+
+impl FromStr for Eligibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut new = Self::default();
+
+        for item in s.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+            let (list, name) = match item.strip_prefix('!') {
+                Some(name) => (&mut new.deny, name.trim()),
+                None => (&mut new.allow, item),
+            };
+
+            let badge: String = match name.to_ascii_lowercase().as_str() {
+                "all" | "any" | "anyone" | "everyone" => continue,
+                "sub" | "subs" | "subscriber" | "subscribers" => "subscriber".into(),
+                "vip" | "vips" => "vip".into(),
+                "mod" | "mods" | "moderator" | "moderators" => "moderator".into(),
+                "" => return Err(format!("invalid eligibility: {:?}", s)),
+                other => other.into(),
+            };
+
+            list.push(badge);
+        }
+
+        Ok(new)
+    }
+}
+
+impl TryFrom<String> for Eligibility {
+    type Error = String;
+    fn try_from(s: String) -> Result<Self, Self::Error> { s.parse() }
+}
+
+impl From<Eligibility> for String {
+    fn from(rules: Eligibility) -> Self { rules.to_string() }
+}
+
+/// The reason for which an Auction ended.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Closure {
+    /// The Auction ran until the end of its time.
+    #[default]
+    Timer,
+    /// The current price of a Dutch Auction was accepted.
+    Purchase,
+    /// A bid reached the buy-it-now price.
+    BuyNow,
+}
+
+
+/// The reason for which an Auction was paused.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PauseReason {
+    /// An operator paused the Auction by command.
+    Operator,
+    /// The bot lost its connection to chat.
+    Disconnect,
+    /// The bot stopped, and the Auction was recovered when it started again.
+    Restart,
+}
+
+
+/// A period of time during which an Auction was paused.
+#[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "summaries_pascal", serde(rename_all = "PascalCase"))]
+pub struct Pause {
+    pub reason: PauseReason,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by: Option<String>,
+    #[cfg(feature = "chrono")]
+    pub time: DateTime<Utc>,
+    /// Length of the pause, in seconds.
+    pub duration: u64,
+}
+
+
+/// A change to the remaining time of an Auction, made by an operator.
+#[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "summaries_pascal", serde(rename_all = "PascalCase"))]
+pub struct Adjustment {
+    pub by: String,
+    #[cfg(feature = "chrono")]
+    pub time: DateTime<Utc>,
+    /// Seconds remaining before the change.
+    pub before: u64,
+    /// Seconds remaining after the change.
+    pub after: u64,
+}
+
+
+/// Time added to an Auction by its Helmet, in response to a late bid.
+#[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "summaries_pascal", serde(rename_all = "PascalCase"))]
+pub struct Extension {
+    /// The bidder whose bid triggered the Helmet.
+    pub bidder: String,
+    #[cfg(feature = "chrono")]
+    pub time: DateTime<Utc>,
+    /// Seconds remaining before the extension.
+    pub before: u64,
+    /// Seconds remaining after the extension.
+    pub after: u64,
+}
+
+
+/// Settings that control the behavior of an Auction. Initial values are taken
+///     from the Configuration, and may be overridden when the Auction is
+///     started.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct AuctionOptions {
+    pub currency: Currency,
+    pub mode: AuctionMode,
+    pub settlement: Settlement,
+    pub duration: Duration,
+    pub helmet: Duration,
+    pub max_raise: Money,
+    pub min_bid: Money,
+    pub min_raise: Increment,
+    /// The Twitch badges that decide who may bid.
+    pub eligible: Eligibility,
+
+    /// The way in which the Helmet changes the timer.
+    pub helmet_policy: HelmetPolicy,
+    /// Time added by the Helmet under the `Extend` policy, if it should be
+    ///     different from the Helmet value.
+    pub helmet_extension: Option<Duration>,
+    /// A limit on the total time the Helmet may add over the whole Auction.
+    pub helmet_max_total: Option<Duration>,
+    /// A limit on the number of times the Helmet may be triggered.
+    pub helmet_max_count: Option<usize>,
+
+    /// The way in which Bits cheered with a bid are treated.
+    pub bits: BitsMode,
+    /// The value of a single Bit, in the currency of the Auction.
+    pub bit_value: Money,
+
+    /// If this is true, and no explicit opening limit is set, the first bid
+    ///     may not exceed the sum of the minimum bid and the maximum raise.
+    pub enforce_max_raise_on_first: bool,
+    /// An explicit limit for the first bid. If set, this takes precedence over
+    ///     `enforce_max_raise_on_first`.
+    pub max_opening_bid: Option<Money>,
+    /// A hidden minimum for the winning bid. This is never revealed in chat.
+    pub reserve: Option<Money>,
+    /// A price at which a bid wins immediately, ending the Auction.
+    pub buy_now: Option<Money>,
+
+    /// The initial price of a Dutch Auction.
+    pub price_start: Option<Money>,
+    /// The lowest price of a Dutch Auction. Defaults to the minimum bid.
+    pub price_floor: Option<Money>,
+    /// The amount by which the price of a Dutch Auction drops each interval.
+    pub price_step: Money,
+    /// The time between drops in the price of a Dutch Auction.
+    pub price_interval: Duration,
+
+    /// The remaining times at which the time left is announced in chat. If
+    ///     this is None, the default schedule is used.
+    pub announce_at: Option<Vec<Duration>>,
+    /// The number of final seconds that are counted down in chat.
+    pub countdown: u64,
+}
+
+impl AuctionOptions {
+    /// Determine the highest amount that will be accepted as the first bid.
+    pub fn opening_limit(&self) -> Option<Money> {
+        match self.max_opening_bid {
+            Some(limit) => Some(limit),
+            None if self.enforce_max_raise_on_first => {
+                Some(self.min_bid.saturating_add(self.max_raise))
+            }
+            None => None,
+        }
+    }
+
+    pub fn price_floor(&self) -> Money {
+        self.price_floor.unwrap_or(self.min_bid)
+    }
+
+    pub fn price_start(&self) -> Money {
+        self.price_start.unwrap_or_default().max(self.price_floor())
+    }
+
+    /// Determine the total length of a Dutch Auction: One interval for every
+    ///     price from the start down to the floor, inclusive. Returns None if
+    ///     the price would never reach the floor, or if the length is too
+    ///     long to be represented.
+    pub fn dutch_length(&self) -> Option<Duration> {
+        let range: Money = self.price_start().saturating_sub(self.price_floor());
+        let drops: u64 = match self.price_step {
+            _ if range == Money::ZERO => 0,
+            Money::ZERO => return None,
+            step => range.div_ceil(step),
+        };
+
+        let prices: u32 = u32::try_from(drops.checked_add(1)?).ok()?;
+        self.price_interval.checked_mul(prices)
+    }
+
+    /// Determine whether a bid can ever be raised without going over the
+    ///     maximum raise. A percentage only grows with the bid, so the
+    ///     smallest raise ever required is the one over the minimum bid.
+    pub fn raises_possible(&self) -> bool {
+        self.min_raise.over(self.min_bid, self.currency.unit()) <= self.max_raise
+    }
+}
+
+
+/// The timing of an Auction is kept as moments on the monotonic clock, which
+///     cannot be written to a file, so they are skipped when the Auction is
+///     serialized; A [`Snapshot`] carries them separately.
+///
+/// [`Snapshot`]: crate::saving::Snapshot
+#[derive(Clone, Deserialize, Serialize)]
 pub struct Auction {
     pub bids: Vec<Bid>,
     pub prize: Option<String>,
+    pub options: AuctionOptions,
 
-    pub duration: Duration,
-    pub helmet: Duration,
-    pub max_raise: usize,
-    pub min_bid: usize,
+    /// Maximum bids registered for automatic bidding, in order of
+    ///     registration.
+    pub proxies: Vec<Proxy>,
+
+    /// Every price announced during a Dutch Auction.
+    pub prices: Vec<PricePoint>,
+    /// The reason for which the Auction ended early, if it did.
+    pub closure: Option<Closure>,
+
+    /// Every time the Auction was paused, in order.
+    pub pauses: Vec<Pause>,
+    /// Every change made to the remaining time by an operator, in order.
+    pub adjustments: Vec<Adjustment>,
+    /// Every time the Helmet added time, in order.
+    pub extensions: Vec<Extension>,
+    /// Winners who failed to pay, and whose bids no longer count.
+    pub disqualified: Vec<String>,
+
+    /// Number of bids that were refused, for any reason.
+    pub rejected: usize,
+    /// Number of bid attempts ignored for coming too soon after another.
+    pub throttled: usize,
+    /// Number of refused bids that went without a reply, to save on chat.
+    pub muted: usize,
+    /// Number of Bits cheered with bids that were refused.
+    pub bits_refused: u64,
+    /// Channels running this Auction together, if there are more than one.
+    pub channels: Vec<String>,
+    /// The balances from which bids must be paid, if bidders use wallets.
+    #[serde(skip)]
+    pub ledger: Option<Arc<Mutex<Ledger>>>,
+    /// The Channel from which each bidder last bid, by lowercase name.
+    origins: HashMap<String, String>,
+    /// The login of each bidder whose name is known to differ from it, by
+    ///     lowercase name. Balances are kept by login.
+    #[serde(default)]
+    logins: HashMap<String, String>,
+    /// The amount actually taken from the balance of the current winner,
+    ///     which may fall short of the price.
+    #[serde(default)]
+    charged: Money,
+    /// The moment at which the current pause began, if the Auction is paused.
+    #[serde(skip)]
+    paused_at: Option<Instant>,
+    /// The total time added by the Helmet so far.
+    extended: Duration,
+
+    /// The source of the current time for the Auction.
+    #[serde(skip, default = "SystemClock::shared")]
+    pub clock: Arc<dyn Clock>,
 
+    #[serde(skip, default = "Instant::now")]
     pub time_begin: Instant,
+    #[serde(skip, default = "Instant::now")]
     pub time_close: Instant,
 }
 
 impl Auction {
-    pub fn new(
-        duration: Duration,
-        helmet: Duration,
-        max_raise: usize,
-        min_bid: usize,
+    pub fn new(options: AuctionOptions, prize: Option<String>) -> Self {
+        Self::with_clock(options, prize, SystemClock::shared())
+    }
+
+    /// Create an Auction that reads the time from the given Clock.
+    pub fn with_clock(
+        mut options: AuctionOptions,
         prize: Option<String>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
-        let now = Instant::now();
+        let now = clock.now();
+
+        if options.mode == AuctionMode::Dutch {
+            options.price_interval = options.price_interval
+                .max(Duration::from_secs(1));
+            if let Some(length) = options.dutch_length() {
+                options.duration = length;
+            }
+        }
 
-        Self {
+        let mut new = Self {
             bids: Vec::new(),
             prize,
-            duration,
-            helmet,
-            max_raise,
-            min_bid,
+            proxies: Vec::new(),
+            prices: Vec::new(),
+            closure: None,
+            pauses: Vec::new(),
+            channels: Vec::new(),
+            ledger: None,
+            origins: HashMap::new(),
+            logins: HashMap::new(),
+            charged: Money::ZERO,
+            paused_at: None,
+            adjustments: Vec::new(),
+            extensions: Vec::new(),
+            extended: Duration::ZERO,
+            disqualified: Vec::new(),
+            rejected: 0,
+            throttled: 0,
+            muted: 0,
+            bits_refused: 0,
+            clock,
             time_begin: now,
-            time_close: now + duration,
-        }
+            time_close: now + options.duration,
+            options,
+        };
+
+        new.update_price();
+        new
     }
 
     pub fn add_time(&mut self, time: Duration) {
         self.time_close += time;
     }
 
+    /// Add time to the Auction on behalf of an operator. Returns the new
+    ///     remaining time.
+    pub fn extend(&mut self, by: &str, time: Duration) -> Option<Duration> {
+        let left: Duration = self.time_left()?;
+        self.set_remaining(by, left.saturating_add(time))
+    }
+
+    /// Remove time from the Auction on behalf of an operator. Returns the new
+    ///     remaining time.
+    pub fn shorten(&mut self, by: &str, time: Duration) -> Option<Duration> {
+        let left: Duration = self.time_left()?;
+        self.set_remaining(by, left.saturating_sub(time))
+    }
+
+    /// Replace the remaining time of the Auction on behalf of an operator.
+    ///     Returns the new remaining time, or None if the Auction has ended,
+    ///     or if it is a Dutch Auction, whose length is set by its prices.
+    pub fn set_remaining(&mut self, by: &str, time: Duration) -> Option<Duration> {
+        if self.options.mode == AuctionMode::Dutch {
+            return None;
+        }
+
+        let before: Duration = self.time_left()?;
+
+        info!("Remaining time set to {}s by {}.", time.as_secs(), by);
+        self.time_close = self.now() + time;
+        self.adjustments.push(Adjustment {
+            by: by.to_owned(),
+            #[cfg(feature = "chrono")]
+            time: self.clock.utc().round_subsecs(0),
+            before: before.as_secs(),
+            after: time.as_secs(),
+        });
+
+        Some(time)
+    }
+
+    /// Stop the countdown, and refuse all bids until resumed. Returns false if
+    ///     the Auction is already paused, or has already ended.
+    pub fn pause(&mut self, reason: PauseReason, by: Option<String>) -> bool {
+        if self.paused_at.is_some() || self.time_left().is_none() {
+            return false;
+        }
+
+        info!("Auction paused ({:?}).", reason);
+        self.paused_at = Some(self.clock.now());
+        self.pauses.push(Pause {
+            reason,
+            by,
+            #[cfg(feature = "chrono")]
+            time: self.clock.utc().round_subsecs(0),
+            duration: 0,
+        });
+
+        true
+    }
+
+    /// Restart the countdown from where it was paused. Returns the length of
+    ///     the pause, or None if the Auction was not paused.
+    pub fn resume(&mut self) -> Option<Duration> {
+        let length: Duration = self.clock.now()
+            .saturating_duration_since(self.paused_at.take()?);
+
+        info!("Auction resumed after {}s.", length.as_secs());
+        self.add_time(length);
+
+        if let Some(pause) = self.pauses.last_mut() {
+            pause.duration = length.as_secs();
+        }
+
+        Some(length)
+    }
+
+    /// Set the timing of an Auction recovered from a Snapshot. It opened some
+    ///     time ago, and continues with the time it had left when the bot
+    ///     stopped. Unless an operator had paused it, the time the bot spent
+    ///     stopped is recorded as a pause.
+    pub fn recover(
+        &mut self,
+        opened: Duration,
+        remaining: Duration,
+        downtime: Duration,
+        paused: bool,
+    ) {
+        let now = self.clock.now();
+
+        self.time_begin = now.checked_sub(opened).unwrap_or(now);
+        self.time_close = now + remaining;
+        self.paused_at = paused.then_some(now);
+
+        if !paused {
+            info!("Auction recovered after {}s.", downtime.as_secs());
+            self.pauses.push(Pause {
+                reason: PauseReason::Restart,
+                by: None,
+                #[cfg(feature = "chrono")]
+                time: (self.clock.utc() - chrono::Duration::from_std(downtime)
+                    .unwrap_or_else(|_| chrono::Duration::zero()))
+                    .round_subsecs(0),
+                duration: downtime.as_secs(),
+            });
+        }
+    }
+
+    /// Determine why the Auction is paused, if it is.
+    pub fn paused(&self) -> Option<PauseReason> {
+        self.paused_at?;
+        self.pauses.last().map(|pause| pause.reason)
+    }
+
+    /// The current moment, as far as the Auction is concerned. While the
+    ///     Auction is paused, time does not advance.
+    fn now(&self) -> Instant {
+        self.paused_at.unwrap_or_else(|| self.clock.now())
+    }
+
+    /// Remember the Channel from which a bidder is bidding, so that it can be
+    ///     recorded with their bids, including any placed automatically.
+    fn note_origin(&mut self, name: &str, channel: &str) {
+        if !self.channels.is_empty() {
+            self.origins.insert(name.to_lowercase(), channel.to_owned());
+        }
+    }
+
+    /// Remember the login of a bidder, whose name as shown in chat may be
+    ///     different, so that their balance can be found.
+    pub fn note_login(&mut self, name: &str, login: &str) {
+        let name: String = name.to_lowercase();
+
+        if !login.eq_ignore_ascii_case(&name) {
+            self.logins.insert(name, login.to_ascii_lowercase());
+        }
+    }
+
+    /// Determine the login of a bidder, under which their balance is kept.
+    fn login(&self, name: &str) -> String {
+        let name: String = name.to_lowercase();
+        self.logins.get(&name).cloned().unwrap_or(name)
+    }
+
     pub fn bid(
         &mut self,
         name_new: impl AsRef<str>,
-        bid_new: usize,
+        bid_new: Money,
+        channel: &str,
     ) -> BidResult {
-        let name_new = name_new.as_ref();
+        self.note_origin(name_new.as_ref(), channel);
 
+        if self.closure.is_some() {
+            return BidResult::Closed;
+        }
+
+        if self.paused_at.is_some() {
+            return BidResult::Paused;
+        }
+
+        if let Some(balance) = self.balance(name_new.as_ref()) {
+            if balance < self.cost(bid_new) {
+                info!("Bid by {} refused (above balance).", name_new.as_ref());
+                return BidResult::AboveBalance(balance);
+            }
+        }
+
+        if let Some(price) = self.options.buy_now {
+            if price <= bid_new && self.options.mode != AuctionMode::Dutch {
+                return self.bid_buy_now(name_new.as_ref(), price);
+            }
+        }
+
+        match self.options.mode {
+            AuctionMode::Open => self.bid_open(name_new.as_ref(), bid_new),
+            AuctionMode::Sealed => self.bid_sealed(name_new.as_ref(), bid_new),
+            AuctionMode::Dutch => self.bid_dutch(name_new.as_ref(), bid_new),
+        }
+    }
+
+    /// Determine how much a bid would actually commit the bidder to pay, if
+    ///     it were accepted. This is less than the bid at a buy-it-now price,
+    ///     or at the current price of a Dutch Auction.
+    fn cost(&self, bid: Money) -> Money {
+        match self.options.mode {
+            AuctionMode::Dutch => bid.min(self.price().unwrap_or(bid)),
+            _ => match self.options.buy_now {
+                Some(price) => bid.min(price),
+                None => bid,
+            },
+        }
+    }
+
+    /// Look up the balance of a bidder, if bidders use wallets.
+    pub fn balance(&self, name: &str) -> Option<Money> {
+        self.ledger.as_ref().map(|ledger| ledger.lock().balance(&self.login(name)))
+    }
+
+    /// Place a bid that was made in a message with a cheer. The Bits are
+    ///     recorded with the bid if it is accepted, and counted as refused
+    ///     otherwise.
+    pub fn bid_cheered(
+        &mut self,
+        name_new: impl AsRef<str>,
+        bid_new: Money,
+        channel: &str,
+        bits: u64,
+    ) -> BidResult {
+        let index: usize = self.bids.len();
+        let result: BidResult = self.bid(name_new, bid_new, channel);
+
+        match self.bids.get_mut(index) {
+            Some(bid) if !result.is_rejection() => bid.bits = Some(bits),
+            _ => self.bits_refused = self.bits_refused.saturating_add(bits),
+        }
+
+        result
+    }
+
+    /// Determine the value of some number of Bits.
+    pub fn bits_value(&self, bits: u64) -> Money {
+        self.options.bit_value.saturating_mul(bits)
+    }
+
+    /// Accept the current price of a Dutch Auction.
+    pub fn buy(&mut self, name: impl AsRef<str>, channel: &str) -> BidResult {
+        match self.price() {
+            Some(price) => self.bid(name, price, channel),
+            None => BidResult::Closed,
+        }
+    }
+
+    fn bid_open(&mut self, name_new: &str, bid_new: Money) -> BidResult {
         let first: bool = match self.last_bid() {
             Some(Bid {
                 amount: bid_current,
@@ -94,83 +969,558 @@ impl Auction {
                     return BidResult::DoesNotRaise(*bid_current);
                 }
 
-                if self.max_raise < bid_new.saturating_sub(*bid_current) {
+                let next: Money = self.next_bid();
+
+                if bid_new < next {
+                    info!("Bid by {} refused (below increment).", name_new);
+                    return BidResult::BelowIncrement(next);
+                }
+
+                if self.options.max_raise < bid_new.saturating_sub(*bid_current) {
                     info!("Bid by {} refused (too high).", name_new);
-                    return BidResult::AboveMaximum(self.max_raise);
+                    return BidResult::AboveMaximum(self.options.max_raise);
                 }
 
                 false
             }
             None => {
-                //  TODO: Consult `enforce_max_raise_on_first` config setting.
-                if self.min_bid + self.max_raise < bid_new {
-                    info!("Bid by {} refused (too high).", name_new);
-                    return BidResult::AboveMaximum(self.max_raise);
+                if let Some(limit) = self.options.opening_limit() {
+                    if limit < bid_new {
+                        info!("Bid by {} refused (too high).", name_new);
+                        return BidResult::AboveOpening(limit);
+                    }
                 }
 
                 true
             }
         };
 
-        if bid_new < self.min_bid {
+        if bid_new < self.options.min_bid {
             info!("Bid by {} refused (too low).", name_new);
-            BidResult::BelowMinimum(self.min_bid)
+            BidResult::BelowMinimum(self.options.min_bid)
         } else {
-            info!("New bid: {} by {}.", money!(bid_new), name_new);
-            self.bids.push(Bid {
-                amount: bid_new,
-                bidder: name_new.to_string(),
-                #[cfg(feature = "chrono")]
-                time: Utc::now().round_subsecs(3),
-            });
+            info!("New bid: {} by {}.", self.money(bid_new), name_new);
+            self.place(name_new, bid_new, false);
+            self.deflect_sniper(name_new);
 
-            self.deflect_sniper();
-            BidResult::Ok { first }
+            let proxied = self.resolve_proxies();
+            BidResult::Ok { first, proxied }
         }
     }
 
-    fn deflect_sniper(&mut self) {
-        let now = Instant::now();
+    /// Register a maximum bid, up to which bids will be placed automatically
+    ///     on behalf of the bidder. Replaces any maximum previously registered
+    ///     by the same bidder.
+    pub fn set_proxy(
+        &mut self,
+        name: impl AsRef<str>,
+        ceiling: Money,
+        channel: &str,
+    ) -> ProxyResult {
+        let name: &str = name.as_ref();
+        self.note_origin(name, channel);
+
+        if self.closure.is_some() {
+            return ProxyResult::Closed;
+        }
+
+        if self.paused_at.is_some() {
+            return ProxyResult::Paused;
+        }
+
+        if self.options.mode != AuctionMode::Open {
+            return ProxyResult::Unavailable;
+        }
 
-        if (self.time_close - self.helmet) < now {
-            self.add_time(self.helmet);
+        let lowest: Money = match self.last_bid() {
+            Some(bid) if bid.bidder.eq_ignore_ascii_case(name) => {
+                bid.amount.saturating_add(self.unit())
+            }
+            _ => self.next_bid(),
+        };
+
+        if ceiling < lowest {
+            info!("Maximum bid by {} refused (too low).", name);
+            return ProxyResult::TooLow(lowest);
         }
+
+        if let Some(balance) = self.balance(name) {
+            if balance < self.cost(ceiling) {
+                info!("Maximum bid by {} refused (above balance).", name);
+                return ProxyResult::AboveBalance(balance);
+            }
+        }
+
+        info!("Maximum bid: {} by {}.", self.money(ceiling), name);
+
+        match self.proxies.iter_mut()
+            .find(|proxy| proxy.bidder.eq_ignore_ascii_case(name))
+        {
+            Some(proxy) => proxy.ceiling = ceiling,
+            None => self.proxies.push(Proxy {
+                bidder: name.to_string(),
+                ceiling,
+            }),
+        }
+
+        let proxied = self.resolve_proxies();
+        ProxyResult::Set { proxied }
+    }
+
+    /// Place automatic bids on behalf of registered maximum bids, until none of
+    ///     them can legally outbid the current leader. Returns every bid that
+    ///     was placed.
+    ///
+    /// When two maximum bids are equal, the one registered first wins.
+    fn resolve_proxies(&mut self) -> Vec<(String, Money)> {
+        let mut placed: Vec<(String, Money)> = Vec::new();
+        let unit: Money = self.unit();
+
+        loop {
+            let next: Money = self.next_bid();
+            let last: Option<&Bid> = self.last_bid();
+            let is_leader = |proxy: &Proxy| last
+                .is_some_and(|bid| bid.bidder.eq_ignore_ascii_case(&proxy.bidder));
+
+            //  Registration order is kept as the index, and the sort is stable,
+            //      so that earlier registrations come first among equals.
+            let mut challengers: Vec<(usize, &Proxy)> = self.proxies.iter()
+                .enumerate()
+                .filter(|(_, proxy)| next <= proxy.ceiling && !is_leader(proxy))
+                .collect();
+            challengers.sort_by_key(|(_, proxy)| std::cmp::Reverse(proxy.ceiling));
+
+            let (best_idx, best) = match challengers.first() {
+                Some(&first) => first,
+                None => break,
+            };
+
+            let defender: Option<(usize, &Proxy)> = self.proxies.iter()
+                .enumerate()
+                .find(|(_, proxy)| is_leader(proxy));
+
+            //  The amount needed to beat each opposing maximum. Only the leader
+            //      can have a higher maximum than the best challenger.
+            let beat = |(idx, proxy): (usize, &Proxy)| match best_idx < idx {
+                true => proxy.ceiling,
+                false => proxy.ceiling.saturating_add(unit),
+            };
+
+            let need: Money = challengers.get(1).copied().map(beat)
+                .max(defender.map(beat))
+                .unwrap_or(next)
+                .max(next);
+
+            //  Only a challenger ever steps up; the leader answers on the next
+            //      pass. If the leader cannot be beaten, the challenger goes no
+            //      further than leaves the leader room to raise by the minimum
+            //      increment, so that a large increment cannot hand the lead
+            //      to the lower maximum.
+            let mut target: Money = match defender {
+                Some((_, def)) if best.ceiling < need => best.ceiling.min(
+                    def.ceiling.saturating_sub(self.options.min_raise.over(def.ceiling, unit)),
+                ),
+                _ => need.min(best.ceiling),
+            };
+            let name: String = best.bidder.clone();
+
+            //  Automatic bids never trigger the buy-it-now price.
+            if let Some(price) = self.options.buy_now {
+                target = target.min(price.saturating_sub(unit));
+            }
+
+            match last {
+                Some(bid) => {
+                    target = target.min(bid.amount.saturating_add(self.options.max_raise));
+                }
+                None => if let Some(limit) = self.options.opening_limit() {
+                    target = target.min(limit);
+                }
+            }
+
+            if target < next {
+                break;
+            }
+
+            info!("New bid: {} by {} (proxy).", self.money(target), name);
+            self.place(&name, target, true);
+            self.deflect_sniper(&name);
+            placed.push((name, target));
+        }
+
+        placed
+    }
+
+    /// Determine the absolute amount of a bid that may have been written
+    ///     relative to the current bid. Sealed bids are never revealed, so a
+    ///     raise in a Sealed Auction is counted from zero.
+    pub fn resolve(&self, amount: BidAmount) -> Money {
+        match self.options.mode {
+            AuctionMode::Open => {
+                amount.resolve(self.last_bid().map(|bid| bid.amount), self.next_bid())
+            }
+            AuctionMode::Sealed => amount.resolve(None, self.options.min_bid),
+            AuctionMode::Dutch => amount.resolve(None, self.price().unwrap_or_default()),
+        }
+    }
+
+    /// Determine the lowest amount that would be accepted as the next bid.
+    pub fn next_bid(&self) -> Money {
+        match self.last_bid() {
+            Some(bid) => bid.amount.saturating_add(
+                self.options.min_raise.over(bid.amount, self.unit()),
+            ),
+            None => self.options.min_bid,
+        }
+    }
+
+    fn bid_sealed(&mut self, name_new: &str, bid_new: Money) -> BidResult {
+        if bid_new < self.options.min_bid {
+            info!("Sealed bid by {} refused (too low).", name_new);
+            return BidResult::BelowMinimum(self.options.min_bid);
+        }
+
+        if let Some(limit) = self.options.opening_limit() {
+            if limit < bid_new {
+                info!("Sealed bid by {} refused (too high).", name_new);
+                return BidResult::AboveOpening(limit);
+            }
+        }
+
+        let revised: bool = self.bids.iter()
+            .any(|bid| bid.bidder.eq_ignore_ascii_case(name_new));
+
+        //  A sealed bid does not reveal anything to chat, so there is nothing
+        //      for a sniper to gain, and no need for a Helmet.
+        info!("Sealed bid: {} by {}.", self.money(bid_new), name_new);
+        self.place(name_new, bid_new, false);
+        BidResult::Sealed { revised }
+    }
+
+    /// Accept a bid at the buy-it-now price, ending the Auction. This bypasses
+    ///     the usual limits on raises, since the price is known in advance.
+    fn bid_buy_now(&mut self, name_new: &str, price: Money) -> BidResult {
+        if self.options.mode == AuctionMode::Open {
+            if let Some(bid) = self.last_bid() {
+                if name_new.eq_ignore_ascii_case(&bid.bidder) {
+                    info!("Bid by {} refused (repeat).", name_new);
+                    return BidResult::RepeatBidder(bid.amount);
+                }
+            }
+        }
+
+        if price < self.options.min_bid {
+            info!("Bid by {} refused (too low).", name_new);
+            return BidResult::BelowMinimum(self.options.min_bid);
+        }
+
+        info!("Buy-it-now: {} by {}.", self.money(price), name_new);
+        self.place(name_new, price, false);
+        self.closure = Some(Closure::BuyNow);
+        BidResult::BuyNow(price)
+    }
+
+    fn bid_dutch(&mut self, name_new: &str, bid_new: Money) -> BidResult {
+        let price: Money = match self.price() {
+            Some(price) => price,
+            None => return BidResult::Closed,
+        };
+
+        if bid_new < price {
+            info!("Bid by {} refused (below price).", name_new);
+            return BidResult::BelowPrice(price);
+        }
+
+        info!("Purchase: {} by {}.", self.money(price), name_new);
+        self.place(name_new, price, false);
+        self.closure = Some(Closure::Purchase);
+        BidResult::Bought(price)
+    }
+
+    fn place(&mut self, name: &str, amount: Money, proxy: bool) {
+        self.bids.push(Bid {
+            amount,
+            bidder: name.to_string(),
+            #[cfg(feature = "chrono")]
+            time: self.clock.utc().round_subsecs(3),
+            proxy,
+            channel: self.origins.get(&name.to_lowercase()).cloned(),
+            bits: None,
+        });
+    }
+
+    /// Add time to the Auction if a bid arrived with less time remaining than
+    ///     the Helmet value, according to the Helmet policy and its limits.
+    fn deflect_sniper(&mut self, bidder: &str) {
+        let helmet: Duration = self.options.helmet;
+        let before: Duration = match self.time_left() {
+            Some(left) if left < helmet => left,
+            _ => return,
+        };
+
+        if let Some(max) = self.options.helmet_max_count {
+            if max <= self.extensions.len() {
+                return;
+            }
+        }
+
+        let mut added: Duration = match self.options.helmet_policy {
+            HelmetPolicy::Extend => self.options.helmet_extension.unwrap_or(helmet),
+            HelmetPolicy::Reset => helmet - before,
+        };
+
+        if let Some(max) = self.options.helmet_max_total {
+            added = added.min(max.saturating_sub(self.extended));
+        }
+
+        //  A bid moments after a reset would otherwise add a sliver of time,
+        //      and count as an extension of its own.
+        if added < Duration::from_secs(1) {
+            return;
+        }
+
+        self.add_time(added);
+        self.extended += added;
+        self.extensions.push(Extension {
+            bidder: bidder.to_owned(),
+            #[cfg(feature = "chrono")]
+            time: self.clock.utc().round_subsecs(0),
+            before: before.as_secs(),
+            after: (before + added).as_secs(),
+        });
     }
 
     pub fn last_bid(&self) -> Option<&Bid> {
         self.bids.last()
     }
 
+    /// Find the bid that would win the Auction if it were to end now.
+    pub fn leading_bid(&self) -> Option<&Bid> {
+        self.standings().into_iter().next()
+    }
+
+    /// Collect the most recent bid of every bidder, ordered from highest to
+    ///     lowest. Equal bids are ordered by which was submitted first.
+    pub fn standings(&self) -> Vec<&Bid> {
+        let mut latest: Vec<(usize, &Bid)> = Vec::new();
+
+        for (idx, bid) in self.bids.iter().enumerate().rev() {
+            if !self.is_disqualified(&bid.bidder)
+                && !latest.iter().any(|(_, b)| b.bidder.eq_ignore_ascii_case(&bid.bidder))
+            {
+                latest.push((idx, bid));
+            }
+        }
+
+        latest.sort_by(|(idx_a, a), (idx_b, b)| {
+            b.amount.cmp(&a.amount).then(idx_a.cmp(idx_b))
+        });
+
+        latest.into_iter().map(|(_, bid)| bid).collect()
+    }
+
+    /// Determine the current price of a Dutch Auction. The price is measured
+    ///     backwards from the closing time, so that any time added to the
+    ///     Auction also holds the price where it is.
+    pub fn price(&self) -> Option<Money> {
+        if self.options.mode != AuctionMode::Dutch {
+            return None;
+        }
+
+        let left: Duration = self.time_left()?;
+        let elapsed: Duration = self.options.duration.saturating_sub(left);
+        let drops = elapsed.as_millis() / self.options.price_interval.as_millis();
+        let drop = self.options.price_step.saturating_mul(drops as u64);
+
+        Some(self.options.price_start()
+            .saturating_sub(drop)
+            .max(self.options.price_floor()))
+    }
+
+    /// Record the current price of a Dutch Auction if it has changed since
+    ///     the last time it was recorded. Returns the new price, if any.
+    pub fn update_price(&mut self) -> Option<Money> {
+        let price: Money = self.price()?;
+
+        match self.prices.last() {
+            Some(last) if last.price == price => None,
+            _ => {
+                self.prices.push(PricePoint {
+                    price,
+                    #[cfg(feature = "chrono")]
+                    time: self.clock.utc().round_subsecs(3),
+                });
+
+                Some(price)
+            }
+        }
+    }
+
+    /// Determine how long remains until the Auction ends. Returns None once no
+    ///     time remains, as when an operator takes away all of it.
+    pub fn time_left(&self) -> Option<Duration> {
+        match self.closure {
+            Some(..) => None,
+            None => self.time_close.checked_duration_since(self.now())
+                .filter(|left| !left.is_zero()),
+        }
+    }
+
     pub fn remaining(&self) -> Option<Duration> {
-        self.time_close.checked_duration_since(Instant::now())
-            .map(|d| Duration::new(d.as_secs(), 0))
+        self.time_left().map(|d| Duration::new(d.as_secs(), 0))
+    }
+
+    pub fn is_disqualified(&self, name: &str) -> bool {
+        self.disqualified.iter().any(|dq| dq.eq_ignore_ascii_case(name))
+    }
+
+    /// Disqualify the winner of a finished Auction, so that the prize goes to
+    ///     the next-highest bidder instead. Returns the name of the
+    ///     disqualified winner, along with the new winner, if there is one.
+    pub fn reassign(&mut self) -> Option<(String, Option<Winner>)> {
+        if self.time_left().is_some() {
+            return None;
+        }
+
+        let Winner { name, .. } = self.winner()?;
+
+        info!("Winner {} disqualified.", name);
+        self.disqualified.push(name.clone());
+
+        //  The disqualified winner never received the Prize, so they get back
+        //      what was taken from them, and the new winner is charged instead.
+        if let Some(ledger) = &self.ledger {
+            ledger.lock().grant(&self.login(&name), std::mem::take(&mut self.charged));
+        }
+
+        self.charge_winner();
+        Some((name, self.winner()))
+    }
+
+    /// Take the price of the Prize from the balance of the winner, if bidders
+    ///     use wallets.
+    pub fn charge_winner(&mut self) {
+        if let (Some(ledger), Some(winner)) = (&self.ledger, self.winner()) {
+            let Winner { name, price_paid, .. } = winner;
+            let login: String = self.login(&name);
+            let mut ledger = ledger.lock();
+            let available: Money = ledger.balance(&login);
+
+            if available < price_paid {
+                warn!("Winner {} cannot cover the full price from their balance.", name);
+            }
+
+            self.charged = available.min(price_paid);
+            let balance: Money = ledger.revoke(&login, price_paid);
+            info!(
+                "Charged {} to {}, leaving {}.",
+                self.money(self.charged), name, self.money(balance),
+            );
+        }
     }
 
-    pub fn finish(self) -> AuctionFinished { self.into() }
+    pub fn finish(&self) -> AuctionFinished { self.clone().into() }
 }
 
 impl Auction {
     pub fn describe(&self) -> String {
-        format!("Auction{}", self.for_prize())
+        match self.options.mode {
+            AuctionMode::Open => format!("Auction{}", self.for_prize()),
+            AuctionMode::Sealed => format!("Sealed Auction{}", self.for_prize()),
+            AuctionMode::Dutch => format!("Dutch Auction{}", self.for_prize()),
+        }
     }
 
     pub fn explain(&self, prefix: &str, verb: &str) -> String {
+        if self.options.mode == AuctionMode::Dutch {
+            return format!(
+                "ATTENTION: A Dutch Auction is NOW OPEN{prize}. The price starts \
+                at {start}, and every {interval} it will drop by {step}, down to \
+                a minimum of {floor}. The FIRST person to post '{prefix}buy' \
+                will win, and will have to {verb} the price at that moment in \
+                order to claim their prize. Focus on this chat, NOT any 'live' \
+                video, since there may be a delay. I will announce every new \
+                price in chat.{eligible}",
+                eligible = match self.options.eligible.is_open() {
+                    true => String::new(),
+                    false => format!(" {}", self.options.eligible.explain()),
+                },
+                floor = self.money(self.options.price_floor()),
+                interval = humantime::format_duration(self.options.price_interval),
+                prefix = prefix,
+                prize = self.for_prize(),
+                start = self.money(self.options.price_start()),
+                step = self.money(self.options.price_step),
+                verb = verb,
+            );
+        }
+
+        let mut payment: String = match self.options.settlement {
+            Settlement::First => format!(
+                "they will have to {verb} that amount in order to claim their \
+                prize",
+            ),
+            Settlement::Second => format!(
+                "they will only have to {verb} the amount of the second-highest \
+                bid (or the minimum bid, if nobody else bids) in order to claim \
+                their prize",
+            ),
+        };
+
+        if let Some(price) = self.options.buy_now {
+            payment.push_str(&format!(
+                ". Anyone who bids {} or more wins instantly",
+                self.money(price),
+            ));
+        }
+
+        if !self.options.eligible.is_open() {
+            payment.push_str(&format!(". {}", self.options.eligible.explain()
+                .trim_end_matches('.')));
+        }
+
+        if self.options.reserve.is_some() {
+            payment.push_str(
+                ". A hidden reserve price applies: If no bid reaches it, there \
+                will be no winner",
+            );
+        }
+
+        if self.options.mode == AuctionMode::Sealed {
+            return format!(
+                "ATTENTION: A Sealed Auction will now run for {time}. Submit a \
+                bid by whispering '{prefix}bid <amount>' to me. Bids are SECRET: \
+                I will not reveal any bid until the end. You may change your bid \
+                at any time by bidding again, and only your latest bid counts. \
+                When time runs out, the person with the highest bid will be the \
+                winner, and {payment}. Bidding starts at {min_bid}, and is NOW \
+                OPEN.",
+                min_bid = self.money(self.options.min_bid),
+                payment = payment,
+                prefix = prefix,
+                time = humantime::format_duration(
+                    self.time_close.saturating_duration_since(self.time_begin)
+                ),
+            );
+        }
+
         format!(
             "ATTENTION: An Auction will now run for {time}. Submit a bid by \
             posting '{prefix}bid <amount>'. Focus on this chat, NOT any 'live' \
             video, since there may be a delay. I will confirm bids in chat. At \
             the end, I will do a final countdown, after which the Auction will \
             be over. The person with the highest bid at that time will be the \
-            winner, and they will have to {verb} that amount in order to claim \
-            their prize. Bidding starts at {min_bid}, and is NOW OPEN.",
+            winner, and {payment}. Each new bid must beat the last by at least \
+            {min_raise}. Bidding starts at {min_bid}, and is NOW OPEN.",
             // auction = self.describe(),
-            // max_raise = money!(self.max_raise),
-            min_bid = money!(self.min_bid),
+            // max_raise = self.money(self.options.max_raise),
+            min_bid = self.money(self.options.min_bid),
+            min_raise = self.options.min_raise.format(&self.options.currency),
+            payment = payment,
             prefix = prefix,
             time = humantime::format_duration(
                 self.time_close.saturating_duration_since(self.time_begin)
             ),
-            verb = verb,
         )
     }
 
@@ -180,16 +1530,72 @@ impl Auction {
             .unwrap_or_default()
     }
 
+    /// Write an amount in the currency of this Auction.
+    pub fn money(&self, amount: Money) -> String {
+        self.options.currency.format(amount)
+    }
+
+    fn unit(&self) -> Money {
+        self.options.currency.unit()
+    }
+
+    /// Determine the amount that the current leader would have to pay if the
+    ///     Auction were to end now.
+    pub fn price_paid(&self) -> Option<Money> {
+        let standings: Vec<&Bid> = self.standings();
+        let leader: &Bid = standings.first()?;
+
+        //  A runner-up, having been offered the prize after the fact, pays the
+        //      amount of their own bid.
+        if self.options.mode == AuctionMode::Dutch
+            || self.closure == Some(Closure::BuyNow)
+            || !self.disqualified.is_empty()
+        {
+            return Some(leader.amount);
+        }
+
+        Some(match self.options.settlement {
+            Settlement::First => leader.amount,
+            Settlement::Second => match standings.get(1) {
+                Some(second) => second.amount,
+                None => self.options.min_bid,
+            }.max(self.options.reserve.unwrap_or_default()),
+        })
+    }
+
+    /// Check whether the leading bid meets the reserve price, if there is one.
+    ///     Dutch Auctions have a price floor instead, and ignore the reserve.
+    pub fn reserve_met(&self) -> bool {
+        match self.options.reserve {
+            Some(reserve) if self.options.mode != AuctionMode::Dutch => {
+                self.leading_bid().is_some_and(|bid| reserve <= bid.amount)
+            }
+            _ => true,
+        }
+    }
+
     pub fn winner(&self) -> Option<Winner> {
-        let Bid { amount, ref bidder, .. } = *self.last_bid()?;
+        if !self.reserve_met() {
+            return None;
+        }
+
+        let Bid { amount, ref bidder, ref channel, .. } = *self.leading_bid()?;
+        let price_paid = self.price_paid()?;
         let bid_count = self.bids.iter()
             .filter(|bid| bid.bidder.eq_ignore_ascii_case(bidder))
             .count();
+        let bits: u64 = self.bids.iter()
+            .filter(|bid| bid.bidder.eq_ignore_ascii_case(bidder))
+            .filter_map(|bid| bid.bits)
+            .sum();
 
         Some(Winner {
             name: bidder.clone(),
+            channel: channel.clone(),
             amount,
+            price_paid,
             bid_count,
+            bits: (0 < bits).then_some(bits),
         })
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::{Arc, atomic::{AtomicBool, Ordering::SeqCst}};
 use twitchchat::{
-    commands::{privmsg, reply},
+    commands::{privmsg, reply, whisper},
     messages::Privmsg,
     runner::{self, AsyncRunner, NotifyHandle},
     writer::{AsyncWriter, MpscWriter},
@@ -36,6 +36,13 @@ impl Response {
             Self::Reply(text) => text,
         }
     }
+
+    pub fn into_text(self) -> String {
+        match self {
+            Self::Message(text) => text,
+            Self::Reply(text) => text,
+        }
+    }
 }
 
 
@@ -63,6 +70,10 @@ impl Client {
         })
     }
 
+    pub fn channel(&self) -> &str {
+        &self.channel
+    }
+
     pub fn is_running(&self) -> bool {
         self.running.load(SeqCst)
     }
@@ -93,6 +104,21 @@ impl Client {
         }
     }
 
+    pub async fn whisper(
+        &mut self,
+        name: &str,
+        text: impl AsRef<str>,
+    ) -> std::io::Result<()> {
+        if self.is_running() {
+            chat!("(-> @{}) {:?}", name, text.as_ref());
+            self.writer.encode(whisper(name, text.as_ref())).await
+        } else {
+            chat!("(-| @{}) {:?}", name, text.as_ref());
+            warn!("Cannot send message: Client is closed.");
+            Ok(())
+        }
+    }
+
     pub async fn quit(self) -> bool {
         self.running.swap(false, SeqCst) && self.handle_quit.notify().await
     }
@@ -0,0 +1,85 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+
+
+/// A source of the current time. Auctions read the time only through a Clock,
+///     so that they can be run on one that is moved by hand, without waiting.
+pub trait Clock: Send + Sync {
+    /// The current moment, for measuring time.
+    fn now(&self) -> Instant;
+
+    /// The current time as the system reports it, for saving to files.
+    fn system(&self) -> SystemTime;
+
+    /// The current date and time, for records.
+    #[cfg(feature = "chrono")]
+    fn utc(&self) -> DateTime<Utc>;
+}
+
+
+/// The real time, as given by the system.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    pub fn shared() -> Arc<dyn Clock> { Arc::new(Self) }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant { Instant::now() }
+
+    fn system(&self) -> SystemTime { SystemTime::now() }
+
+    #[cfg(feature = "chrono")]
+    fn utc(&self) -> DateTime<Utc> { Utc::now() }
+}
+
+
+/// A Clock that stands still until it is advanced. It starts at the moment it
+///     is created.
+pub struct ManualClock {
+    start: Instant,
+    start_system: SystemTime,
+    #[cfg(feature = "chrono")]
+    start_utc: DateTime<Utc>,
+    /// The time the Clock has been advanced since it was created.
+    offset: Mutex<Duration>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            start_system: SystemTime::now(),
+            #[cfg(feature = "chrono")]
+            start_utc: Utc::now(),
+            offset: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Move the Clock forward.
+    pub fn advance(&self, time: Duration) {
+        *self.offset.lock() += time;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self { Self::new() }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant { self.start + *self.offset.lock() }
+
+    fn system(&self) -> SystemTime { self.start_system + *self.offset.lock() }
+
+    #[cfg(feature = "chrono")]
+    fn utc(&self) -> DateTime<Utc> {
+        self.start_utc + chrono::Duration::from_std(*self.offset.lock())
+            .unwrap_or_else(|_| chrono::Duration::zero())
+    }
+}
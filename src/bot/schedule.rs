@@ -0,0 +1,161 @@
+use std::{sync::Arc, time::{Duration, Instant}};
+#[cfg(feature = "chrono")]
+use chrono::{Local, LocalResult, NaiveTime, TimeZone};
+use parking_lot::Mutex;
+use crate::saving::Ledger;
+use super::{
+    auction::{Auction, AuctionMode, AuctionOptions},
+    clock::Clock,
+    util::parse_times,
+};
+
+
+/// Everything needed to start an Auction: The options given when it was
+///     requested, on top of the Configuration of its channel.
+#[derive(Clone)]
+pub struct AuctionSetup {
+    pub options: AuctionOptions,
+    pub prize: Option<String>,
+    pub verb: String,
+    /// Channels that will run the Auction together, if there are more than
+    ///     one.
+    pub channels: Vec<String>,
+    /// The balances from which bids must be paid, if bidders use wallets.
+    pub ledger: Option<Arc<Mutex<Ledger>>>,
+    /// The source of the current time for the Auction.
+    pub clock: Arc<dyn Clock>,
+}
+
+impl AuctionSetup {
+    /// Create the Auction. Returns it along with the verb for its winner.
+    pub fn begin(self) -> (Auction, String) {
+        let mut auction = Auction::with_clock(self.options, self.prize, self.clock);
+        auction.channels = self.channels;
+        auction.ledger = self.ledger;
+
+        (auction, self.verb)
+    }
+
+    pub fn describe(&self) -> String {
+        let prize: String = self.prize.as_ref()
+            .map(|s| format!(" for {s}"))
+            .unwrap_or_default();
+
+        match self.options.mode {
+            AuctionMode::Open => format!("Auction{}", prize),
+            AuctionMode::Sealed => format!("Sealed Auction{}", prize),
+            AuctionMode::Dutch => format!("Dutch Auction{}", prize),
+        }
+    }
+}
+
+
+/// An Auction waiting to be started automatically at a later time.
+#[derive(Clone)]
+pub struct Scheduled {
+    pub setup: AuctionSetup,
+    /// The operator who scheduled the Auction.
+    pub by: String,
+    /// The moment at which the Auction starts, by the Clock of the Auction.
+    pub start: Instant,
+    /// Lead times, in seconds, for which the Auction has been announced.
+    announced: Vec<u64>,
+}
+
+impl Scheduled {
+    /// Schedule an Auction. Lead times that have already passed are not
+    ///     announced, since the operator has just done so.
+    pub fn new(setup: AuctionSetup, by: String, start: Instant, leads: &[Duration]) -> Self {
+        let mut new = Self { setup, by, start, announced: Vec::new() };
+        new.announce(leads);
+        new
+    }
+
+    /// Determine how long remains until the Auction starts, by the Clock of
+    ///     the Auction.
+    pub fn until(&self) -> Duration {
+        self.start.saturating_duration_since(self.setup.clock.now())
+    }
+
+    pub fn is_due(&self) -> bool {
+        self.start <= self.setup.clock.now()
+    }
+
+    /// Check whether a lead time has been reached since the last time this
+    ///     was called. Returns the time until the start, if it should be
+    ///     announced.
+    pub fn announce(&mut self, leads: &[Duration]) -> Option<Duration> {
+        let until: Duration = self.until();
+        let mut due: bool = false;
+
+        for lead in leads.iter().filter(|lead| until <= **lead) {
+            if !self.announced.contains(&lead.as_secs()) {
+                self.announced.push(lead.as_secs());
+                due = true;
+            }
+        }
+
+        due.then(|| Duration::from_secs(until.as_secs() + 1))
+    }
+}
+
+
+/// A reason that the start of an Auction could not be read.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WhenError {
+    /// The arguments do not say when the Auction should start.
+    Invalid,
+    /// The time of day will not occur, because the clocks skip over it.
+    #[cfg(feature = "chrono")]
+    Skipped,
+    /// The time of day will occur twice, because the clocks go back over it.
+    #[cfg(feature = "chrono")]
+    Repeated,
+}
+
+
+/// Read the time at which an Auction should start, from the start of the
+///     arguments to a command. This may be a delay, like `in 1h 30m`, or a time
+///     of day, like `20:30`, which is taken to be the next time the clock
+///     reads that time. Returns the delay until the start, and the arguments
+///     that remain.
+pub fn parse_when<'a, 'b>(
+    args: &'a [&'b str],
+) -> Result<(Duration, &'a [&'b str]), WhenError> {
+    match args {
+        //  A delay may be written across several words, like `in 1h 30m`.
+        ["in", rest @ ..] => {
+            let parts: Vec<Vec<Duration>> = rest.iter()
+                .map_while(|word| parse_times(word).filter(|times| !times.is_empty()))
+                .collect();
+
+            match parts.is_empty() {
+                true => Err(WhenError::Invalid),
+                false => Ok((parts.iter().flatten().sum(), &rest[parts.len()..])),
+            }
+        }
+        #[cfg(feature = "chrono")]
+        [clock, rest @ ..] => {
+            let time = NaiveTime::parse_from_str(clock, "%H:%M")
+                .map_err(|_| WhenError::Invalid)?;
+            let now = Local::now();
+            let mut date = now.date_naive();
+
+            if date.and_time(time) <= now.naive_local() {
+                date += chrono::Duration::days(1);
+            }
+
+            let start = match Local.from_local_datetime(&date.and_time(time)) {
+                LocalResult::Single(start) => start,
+                LocalResult::Ambiguous(..) => return Err(WhenError::Repeated),
+                LocalResult::None => return Err(WhenError::Skipped),
+            };
+
+            match (start - now).to_std() {
+                Ok(delay) => Ok((delay, rest)),
+                Err(..) => Err(WhenError::Invalid),
+            }
+        }
+        _ => Err(WhenError::Invalid),
+    }
+}
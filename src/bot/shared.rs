@@ -0,0 +1,178 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock},
+};
+use parking_lot::Mutex;
+use crate::saving::Ledger;
+use super::{auction::Auction, client::Client, schedule::Scheduled};
+
+
+/// Every Auction group in this process, by Auction ID.
+static REGISTRY: LazyLock<Mutex<HashMap<String, Arc<Group>>>> = LazyLock::new(Default::default);
+
+
+/// The group for which a Whisper that does not name a Channel is meant.
+pub enum Addressee {
+    Group(Arc<Group>),
+    /// More than one group could be meant, so the Channel must be named.
+    Unclear,
+    /// No group could be meant.
+    Nobody,
+}
+
+impl Addressee {
+    /// Decide the group for a Whisper that does not name a Channel. This is
+    ///     the only group with a running Auction. If the Whisper does not need
+    ///     an Auction, and none are running, it is the only group connected.
+    pub fn find(needs_auction: bool) -> Self {
+        let connected: Vec<Arc<Group>> = REGISTRY.lock().values()
+            .filter(|group| !group.clients.lock().is_empty())
+            .cloned()
+            .collect();
+        let running: Vec<&Arc<Group>> = connected.iter()
+            .filter(|group| group.auction.lock().is_some())
+            .collect();
+
+        match (running.as_slice(), connected.as_slice()) {
+            ([group], _) => Self::Group(Arc::clone(group)),
+            ([], [group]) if !needs_auction => Self::Group(group.clone()),
+            ([], _) if needs_auction => Self::Nobody,
+            ([], []) => Self::Nobody,
+            _ => Self::Unclear,
+        }
+    }
+}
+
+
+/// Determine whether a Channel is the one that answers a Whisper which cannot
+///     be handled by a single group. This is the first connected Channel, by
+///     name, across every group, so that the answer is only sent once.
+pub fn answers_whispers(channel: &str) -> bool {
+    let registry = REGISTRY.lock();
+    let first: Option<String> = registry.values()
+        .flat_map(|group| group.clients.lock()
+            .iter()
+            .map(|client| client.channel().to_ascii_lowercase())
+            .collect::<Vec<_>>())
+        .min();
+
+    first.is_some_and(|first| first.eq_ignore_ascii_case(channel))
+}
+
+
+/// The state of an Auction, held jointly by every Channel that runs it. A
+///     Channel that does not share its Auction is the only member of its own
+///     group.
+#[derive(Default)]
+pub struct Group {
+    pub auction: Arc<Mutex<Option<Auction>>>,
+    /// The most recent Auction to have ended, kept in case its winner must be
+    ///     replaced.
+    pub finished: Arc<Mutex<Option<Auction>>>,
+    /// Auctions waiting to be started automatically, in order of their start.
+    pub schedule: Arc<Mutex<Vec<Scheduled>>>,
+
+    /// The Auction ID of the group.
+    id: String,
+    /// The balances of bidders, once they have been needed.
+    ledger: Mutex<Option<Arc<Mutex<Ledger>>>>,
+    /// Every Channel in the group, in the order they were joined.
+    channels: Mutex<Vec<String>>,
+    /// Clients of the Channels that are currently connected, in the order
+    ///     they connected.
+    clients: Mutex<Vec<Client>>,
+}
+
+impl Group {
+    /// Add a Channel to the group with the given Auction ID, creating the
+    ///     group if it does not exist yet.
+    pub fn join(id: &str, channel: &str) -> Arc<Self> {
+        let group: Arc<Self> = REGISTRY.lock()
+            .entry(id.to_ascii_lowercase())
+            .or_insert_with(|| Arc::new(Self { id: id.to_owned(), ..Self::default() }))
+            .clone();
+
+        let mut channels = group.channels.lock();
+
+        if !channels.iter().any(|c| c.eq_ignore_ascii_case(channel)) {
+            channels.push(channel.to_owned());
+        }
+
+        drop(channels);
+        group
+    }
+
+    /// Get the Ledger of the group, reading it from its file the first time.
+    ///     Returns None if it cannot be read, so that bids are not checked
+    ///     against an empty Ledger by mistake.
+    pub fn ledger(&self) -> Option<Arc<Mutex<Ledger>>> {
+        let mut ledger = self.ledger.lock();
+
+        if ledger.is_none() {
+            match Ledger::load(&self.id) {
+                Ok(loaded) => *ledger = Some(Arc::new(Mutex::new(loaded))),
+                Err(e) => err!("Failed to load Ledger for {}: {}", self.id, e),
+            }
+        }
+
+        ledger.clone()
+    }
+
+    pub fn channels(&self) -> Vec<String> {
+        self.channels.lock().clone()
+    }
+
+    pub fn includes(&self, channel: &str) -> bool {
+        self.channels.lock().iter().any(|c| c.eq_ignore_ascii_case(channel))
+    }
+
+    /// Determine whether more than one Channel runs this Auction.
+    pub fn is_shared(&self) -> bool {
+        1 < self.channels.lock().len()
+    }
+
+    /// Register the Client of a Channel that has just connected.
+    pub fn connect(&self, client: Client) {
+        let mut clients = self.clients.lock();
+
+        clients.retain(|c| !c.channel().eq_ignore_ascii_case(client.channel()));
+        clients.push(client);
+    }
+
+    /// Remove the Client of a Channel that has disconnected. Returns true if
+    ///     any other Channel in the group is still connected.
+    pub fn disconnect(&self, channel: &str) -> bool {
+        let mut clients = self.clients.lock();
+
+        clients.retain(|c| !c.channel().eq_ignore_ascii_case(channel));
+        !clients.is_empty()
+    }
+
+    /// Determine whether a Channel is the one that keeps time for the group.
+    ///     This is the earliest connected Channel, so that only one of them
+    ///     runs the timer and sends the regular announcements.
+    pub fn leads(&self, channel: &str) -> bool {
+        match self.clients.lock().first() {
+            Some(client) => client.channel().eq_ignore_ascii_case(channel),
+            None => false,
+        }
+    }
+
+    /// Collect the Clients of every connected Channel except the given one.
+    pub fn others(&self, channel: &str) -> Vec<Client> {
+        self.clients.lock().iter()
+            .filter(|c| !c.channel().eq_ignore_ascii_case(channel))
+            .cloned()
+            .collect()
+    }
+
+    /// Send a message to every other connected Channel in the group. Failures
+    ///     are logged, but do not stop the message reaching the rest.
+    pub async fn relay(&self, channel: &str, text: &str) {
+        for mut client in self.others(channel) {
+            if let Err(e) = client.send(text).await {
+                warn!("Failed to relay message to #{}: {}", client.channel(), e);
+            }
+        }
+    }
+}
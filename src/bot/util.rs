@@ -1,4 +1,6 @@
-use std::ops::Range;
+use std::{collections::HashMap, ops::Range, time::{Duration, Instant}};
+use humantime::parse_duration;
+use crate::currency::{Currency, Money, ParseMoneyError};
 
 
 const fn is_quote(char: u8) -> bool {
@@ -107,3 +109,132 @@ pub fn to_end_unquoted<'a>(main: &'a str, arr: &[&'a str]) -> Option<&'a str> {
         [first, ..] => substring_to_end(main, first),
     }
 }
+
+
+/// Read a comma-separated list of lengths of time, each either a number of
+///     seconds, or written out, like `5m`. Returns None if any of them is
+///     invalid.
+pub fn parse_times(text: &str) -> Option<Vec<Duration>> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| match part.parse() {
+            Ok(secs) => Some(Duration::from_secs(secs)),
+            Err(_) => parse_duration(part).ok(),
+        })
+        .collect()
+}
+
+
+/// The time of the latest bid attempt by each user, by lowercase name, used to
+///     ignore attempts that come too soon after another.
+#[derive(Clone, Debug, Default)]
+pub struct Cooldowns(HashMap<String, Instant>);
+
+impl Cooldowns {
+    /// Check whether a user is attempting to bid again too soon after their
+    ///     last attempt. Only attempts that are not throttled restart the
+    ///     cooldown, and attempts made before the current Auction began are
+    ///     forgotten, however it was started.
+    pub fn throttle(
+        &mut self,
+        name: &str,
+        cooldown: Duration,
+        begin: Instant,
+        now: Instant,
+    ) -> bool {
+        if cooldown.is_zero() {
+            return false;
+        }
+
+        match self.0.get(&name.to_lowercase()) {
+            Some(&last) if begin <= last && now.saturating_duration_since(last) < cooldown => {
+                true
+            }
+            _ => {
+                self.0.insert(name.to_lowercase(), now);
+                false
+            }
+        }
+    }
+}
+
+
+/// An amount written by a bidder, which may depend on the state of the Auction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BidAmount {
+    /// A specific amount, like `50`, `1.5k` or `$1,200`.
+    Exact(Money),
+    /// An amount to be added to the current bid, like `+50`.
+    Raise(Money),
+    /// The lowest amount that would currently be accepted, written `min`.
+    Minimum,
+}
+
+impl BidAmount {
+    /// Read an amount as written by a bidder. The currency symbol and code are
+    ///     optional, thousands may be separated by commas, and a suffix of `k`
+    ///     or `m` multiplies the amount by a thousand or a million.
+    pub fn parse(text: &str, currency: &Currency) -> Result<Self, ParseMoneyError> {
+        let text: &str = text.trim();
+
+        if ["min", "minimum", "next"].iter().any(|w| text.eq_ignore_ascii_case(w)) {
+            return Ok(Self::Minimum);
+        }
+
+        match text.strip_prefix('+') {
+            Some(raise) => Ok(Self::Raise(parse_shorthand(raise, currency)?)),
+            None => Ok(Self::Exact(parse_shorthand(text, currency)?)),
+        }
+    }
+
+    /// Determine the absolute amount of this bid, given the current bid, if
+    ///     it is known, and the lowest amount that would be accepted.
+    pub fn resolve(self, current: Option<Money>, minimum: Money) -> Money {
+        match self {
+            Self::Exact(amount) => amount,
+            Self::Raise(raise) => current.unwrap_or_default().saturating_add(raise),
+            Self::Minimum => minimum,
+        }
+    }
+}
+
+
+/// Read an absolute amount, which may be written with a currency symbol or
+///     code, separators between thousands, and a suffix of `k` or `m`.
+fn parse_shorthand(text: &str, currency: &Currency) -> Result<Money, ParseMoneyError> {
+    let text: &str = currency.strip(text);
+    let (number, factor): (&str, u64) = match text.char_indices().last() {
+        Some((idx, 'k' | 'K')) => (&text[..idx], 1_000),
+        Some((idx, 'm' | 'M')) => (&text[..idx], 1_000_000),
+        _ => (text, 1),
+    };
+
+    let amount: Money = strip_grouping(number)
+        .ok_or(ParseMoneyError::Invalid)?
+        .parse()?;
+
+    currency.check(amount.cents().checked_mul(factor)
+        .map(Money::from_cents)
+        .ok_or(ParseMoneyError::Invalid)?)
+}
+
+
+/// Remove commas that separate groups of thousands, as in `1,200`. Returns
+///     None if there is a comma anywhere else, since it might instead be meant
+///     as a decimal point.
+fn strip_grouping(text: &str) -> Option<String> {
+    let (whole, frac) = text.split_once('.').unwrap_or((text, ""));
+
+    if !whole.contains(',') {
+        return (!frac.contains(',')).then(|| text.to_owned());
+    }
+
+    let mut groups = whole.split(',');
+    let lead: usize = groups.next()?.len();
+
+    match (1..=3).contains(&lead) && groups.all(|group| group.len() == 3) && !frac.contains(',') {
+        true => Some(text.replace(',', "")),
+        false => None,
+    }
+}
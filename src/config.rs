@@ -7,7 +7,15 @@ use std::{
     time::Duration,
 };
 use directories::ProjectDirs;
+use humantime::{format_duration, parse_duration};
 use twitchchat::twitch::{UserConfig, UserConfigError};
+use crate::{
+    bot::auction::{
+        AuctionMode, AuctionOptions, BitsMode, Eligibility, HelmetPolicy, Increment,
+        Settlement,
+    },
+    currency::{Currency, Money},
+};
 
 
 macro_rules! filename {($name:expr) => {concat!($name, ".toml")}}
@@ -17,6 +25,25 @@ macro_rules! filename {($name:expr) => {concat!($name, ".toml")}}
 const CONFIG_DEFAULT: &str = include_str!(filename!("cfg_default"));
 const CONFIG_PATH: &str = filename!("cfg");
 
+/// Price drop per interval in a Dutch Auction, if not otherwise configured.
+const DEFAULT_PRICE_STEP: Money = Money::whole(5);
+/// Value of a single Bit, if not otherwise configured.
+const DEFAULT_BIT_VALUE: Money = Money::from_cents(1);
+/// Seconds between price drops in a Dutch Auction, if not otherwise configured.
+const DEFAULT_PRICE_INTERVAL: u64 = 10;
+/// Seconds before a scheduled Auction at which it is announced, if not
+///     otherwise configured.
+const DEFAULT_SCHEDULE_ANNOUNCE: [u64; 4] = [1800, 600, 300, 60];
+/// Final seconds of an Auction that are counted down, if not otherwise
+///     configured.
+const DEFAULT_COUNTDOWN: u64 = 5;
+
+
+/// Whether the first bid is held to the maximum raise, if not otherwise
+///     configured. Config files written before the setting existed keep the
+///     limit they always had.
+const fn default_enforce_max_raise_on_first() -> bool { true }
+
 
 fn contains<I, T, U>(sequence: I, want: U) -> bool where
     I: IntoIterator<Item=T>,
@@ -43,13 +70,14 @@ fn get_backup(path: &Path) -> Option<PathBuf> {
 }
 
 
-fn lower(vec: &mut Vec<String>) {
+fn lower(vec: &mut [String]) {
     for name in vec.iter_mut() {
         name.make_ascii_lowercase();
     }
 }
 
 
+#[allow(clippy::large_enum_variant)]
 pub enum ConfigFind {
     DoesNotExist(PathBuf),
     Exists(PathBuf, ConfigOpen),
@@ -67,6 +95,7 @@ impl ConfigFind {
 }
 
 
+#[allow(clippy::large_enum_variant)]
 pub enum ConfigOpen {
     FileInaccessible(std::io::Error),
     FileInvalid(toml::de::Error),
@@ -83,11 +112,53 @@ pub struct ConfigAuth {
 
 #[derive(Clone, Deserialize, Serialize)]
 pub struct ConfigAuction {
+    #[serde(default)]
+    currency: Currency,
+    #[serde(default)]
+    mode: AuctionMode,
+    #[serde(default)]
+    settlement: Settlement,
     duration: u64,
     helmet: u64,
+    #[serde(default)]
+    helmet_policy: HelmetPolicy,
+    helmet_extension: Option<u64>,
+    helmet_max_total: Option<u64>,
+    helmet_max_count: Option<usize>,
+
+    max_raise: Money,
+    min_bid: Money,
+    min_raise: Option<Increment>,
+    #[serde(default)]
+    eligible: Eligibility,
+
+    #[serde(default = "default_enforce_max_raise_on_first")]
+    enforce_max_raise_on_first: bool,
+    max_opening_bid: Option<Money>,
+    reserve: Option<Money>,
+    buy_now: Option<Money>,
 
-    max_raise: usize,
-    min_bid: usize,
+    price_step: Option<Money>,
+    price_interval: Option<u64>,
+
+    #[serde(default)]
+    bits: BitsMode,
+    bit_value: Option<Money>,
+
+    #[serde(default)]
+    wallet: bool,
+
+    #[serde(default)]
+    auto_recover: bool,
+
+    bid_cooldown: Option<u64>,
+    max_rejection_replies: Option<usize>,
+
+    schedule_announce: Option<Vec<u64>>,
+
+    announce: Option<bool>,
+    announce_at: Option<Vec<ConfigDuration>>,
+    countdown: Option<u64>,
 
     summary: bool,
     verb: String,
@@ -112,18 +183,165 @@ pub struct ConfigBot {
 
 #[derive(Clone, Deserialize, Serialize)]
 pub struct ConfigChannel {
+    /// Channels with the same Auction ID share a single Auction.
+    auction_id: Option<String>,
     admins: Option<Vec<String>>,
     #[serde(alias = "blacklist")]
     ignore: Option<Vec<String>>,
 
+    currency: Option<Currency>,
+    mode: Option<AuctionMode>,
+    settlement: Option<Settlement>,
     duration: Option<u64>,
     helmet: Option<u64>,
+    helmet_policy: Option<HelmetPolicy>,
+    helmet_extension: Option<u64>,
+    helmet_max_total: Option<u64>,
+    helmet_max_count: Option<usize>,
+
+    max_raise: Option<Money>,
+    min_bid: Option<Money>,
+    min_raise: Option<Increment>,
+    eligible: Option<Eligibility>,
+
+    enforce_max_raise_on_first: Option<bool>,
+    max_opening_bid: Option<Money>,
+    reserve: Option<Money>,
+    buy_now: Option<Money>,
+
+    price_step: Option<Money>,
+    price_interval: Option<u64>,
+
+    bits: Option<BitsMode>,
+    bit_value: Option<Money>,
+
+    wallet: Option<bool>,
+
+    auto_recover: Option<bool>,
 
-    max_raise: Option<usize>,
-    min_bid: Option<usize>,
+    bid_cooldown: Option<u64>,
+    max_rejection_replies: Option<usize>,
+
+    schedule_announce: Option<Vec<u64>>,
+
+    announce: Option<bool>,
+    announce_at: Option<Vec<ConfigDuration>>,
+    countdown: Option<u64>,
 
     summary: Option<bool>,
     verb: Option<String>,
+
+    #[serde(rename = "preset")]
+    presets: Option<HashMap<String, ConfigPreset>>,
+}
+
+
+/// A length of time in a Configuration file, given either as a number of
+///     seconds, or written out, like `"5m"` or `"1h 30m"`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "ConfigDurationRaw", into = "ConfigDurationRaw")]
+pub struct ConfigDuration(pub Duration);
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum ConfigDurationRaw {
+    Seconds(u64),
+    Written(String),
+}
+
+impl TryFrom<ConfigDurationRaw> for ConfigDuration {
+    type Error = String;
+
+    fn try_from(raw: ConfigDurationRaw) -> Result<Self, Self::Error> {
+        match raw {
+            ConfigDurationRaw::Seconds(secs) => Ok(Self(Duration::from_secs(secs))),
+            ConfigDurationRaw::Written(text) => parse_duration(&text)
+                .map(Self)
+                .map_err(|e| format!("invalid length of time {:?}: {}", text, e)),
+        }
+    }
+}
+
+impl From<ConfigDuration> for ConfigDurationRaw {
+    fn from(duration: ConfigDuration) -> Self {
+        Self::Written(format_duration(duration.0).to_string())
+    }
+}
+
+
+/// A named set of Auction options, which may be selected when an Auction is
+///     started, in place of a long list of flags.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct ConfigPreset {
+    duration: Option<u64>,
+    helmet: Option<u64>,
+    min_bid: Option<Money>,
+    max_raise: Option<Money>,
+    verb: Option<String>,
+    prize: Option<String>,
+}
+
+impl ConfigPreset {
+    /// Replace the options that this preset specifies.
+    pub fn apply(&self, options: &mut AuctionOptions) {
+        if let Some(value) = self.duration {
+            options.duration = Duration::from_secs(value);
+        }
+
+        if let Some(value) = self.helmet {
+            options.helmet = Duration::from_secs(value);
+        }
+
+        if let Some(value) = self.min_bid {
+            options.min_bid = value;
+        }
+
+        if let Some(value) = self.max_raise {
+            options.max_raise = value;
+        }
+    }
+
+    pub fn prize(&self) -> Option<&str> {
+        self.prize.as_deref()
+    }
+
+    pub fn verb(&self) -> Option<&str> {
+        self.verb.as_deref()
+    }
+
+    /// Summarize the options that this preset specifies, for chat.
+    pub fn describe(&self, currency: &Currency) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(value) = self.duration {
+            parts.push(format!("{}s long", value));
+        }
+
+        if let Some(value) = self.helmet {
+            parts.push(format!("{}s helmet", value));
+        }
+
+        if let Some(value) = self.min_bid {
+            parts.push(format!("minimum bid {}", currency.format(value)));
+        }
+
+        if let Some(value) = self.max_raise {
+            parts.push(format!("maximum raise {}", currency.format(value)));
+        }
+
+        if let Some(value) = &self.verb {
+            parts.push(format!("verb {:?}", value));
+        }
+
+        if let Some(value) = &self.prize {
+            parts.push(format!("prize {:?}", value));
+        }
+
+        match parts.is_empty() {
+            true => String::from("no changes"),
+            false => parts.join(", "),
+        }
+    }
 }
 
 
@@ -137,6 +355,9 @@ pub struct Config {
 
     #[serde(rename = "channel")]
     channels: Option<HashMap<String, ConfigChannel>>,
+
+    #[serde(rename = "preset")]
+    presets: Option<HashMap<String, ConfigPreset>>,
 }
 
 
@@ -173,7 +394,7 @@ impl Config {
     pub fn open(path: &Path) -> ConfigOpen {
         use ConfigOpen::*;
 
-        let data = match File::open(&path) {
+        let data = match File::open(path) {
             Ok(mut file) => match file.seek(SeekFrom::End(0)) {
                 Ok(len) => match file.rewind() {
                     Ok(..) => {
@@ -193,11 +414,37 @@ impl Config {
 
         match toml::from_str::<Config>(&data) {
             Err(e) => FileInvalid(e),
-            Ok(mut config) => {
-                config.lower();
-                FileValid(config)
+            Ok(mut config) => match config.validate() {
+                Err(msg) => FileInvalid(serde::de::Error::custom(msg)),
+                Ok(()) => {
+                    config.lower();
+                    FileValid(config)
+                }
+            }
+        }
+    }
+
+    /// Check for settings that are each valid alone, but cannot work together.
+    pub fn validate(&self) -> Result<(), String> {
+        let channels = self.channels.iter().flat_map(|map| map.keys());
+
+        for channel in std::iter::once("").chain(channels.map(String::as_str)) {
+            let opt: AuctionOptions = self.auction_options(channel);
+
+            if !opt.raises_possible() {
+                return Err(format!(
+                    "min_raise of {} is more than max_raise of {}{}",
+                    opt.min_raise.format(&opt.currency),
+                    opt.currency.format(opt.max_raise),
+                    match channel {
+                        "" => String::new(),
+                        _ => format!(" in channel {:?}", channel),
+                    },
+                ));
             }
         }
+
+        Ok(())
     }
 
     pub const fn with_path(self, path: PathBuf) -> ConfigFile {
@@ -220,6 +467,116 @@ impl Config {
             .build()
     }
 
+    pub fn auction_options(&self, channel: &str) -> AuctionOptions {
+        AuctionOptions {
+            currency: self.currency(channel),
+            mode: self.mode(channel),
+            settlement: self.settlement(channel),
+            duration: self.duration(channel),
+            helmet: self.helmet(channel),
+            max_raise: self.max_raise(channel),
+            min_bid: self.min_bid(channel),
+            min_raise: self.min_raise(channel),
+            eligible: self.eligible(channel),
+            helmet_policy: self.helmet_policy(channel),
+            helmet_extension: self.helmet_extension(channel),
+            helmet_max_total: self.helmet_max_total(channel),
+            helmet_max_count: self.helmet_max_count(channel),
+            enforce_max_raise_on_first: self.enforce_max_raise_on_first(channel),
+            max_opening_bid: self.max_opening_bid(channel),
+            reserve: self.reserve(channel),
+            buy_now: self.buy_now(channel),
+            price_start: None,
+            price_floor: None,
+            price_step: self.price_step(channel),
+            price_interval: self.price_interval(channel),
+            bits: self.bits(channel),
+            bit_value: self.bit_value(channel),
+            announce_at: self.announce_at(channel),
+            countdown: self.countdown(channel),
+        }
+    }
+
+    /// Find the ID of the Auction that a Channel runs. Unless it is set to be
+    ///     shared, every Channel runs its own.
+    pub fn auction_id(&self, channel: &str) -> String {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { auction_id: Some(value), .. }) => value.clone(),
+            _ => format!("#{}", channel),
+        }
+    }
+
+    /// Determine whether the time left in an Auction is announced in chat at
+    ///     all.
+    pub fn announce(&self, channel: &str) -> bool {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { announce: Some(value), .. }) => *value,
+            _ => self.auction.announce.unwrap_or(true),
+        }
+    }
+
+    /// Find the remaining times at which the time left in an Auction is
+    ///     announced. Returns None if the default schedule should be used.
+    pub fn announce_at(&self, channel: &str) -> Option<Vec<Duration>> {
+        let times: &[ConfigDuration] = match self.config_channel(channel) {
+            _ if !self.announce(channel) => &[],
+            Some(ConfigChannel { announce_at: Some(value), .. }) => value,
+            _ => self.auction.announce_at.as_deref()?,
+        };
+
+        Some(times.iter().map(|time| time.0).collect())
+    }
+
+    pub fn countdown(&self, channel: &str) -> u64 {
+        match self.config_channel(channel) {
+            _ if !self.announce(channel) => 0,
+            Some(ConfigChannel { countdown: Some(value), .. }) => *value,
+            _ => self.auction.countdown.unwrap_or(DEFAULT_COUNTDOWN),
+        }
+    }
+
+    pub fn auto_recover(&self, channel: &str) -> bool {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { auto_recover: Some(value), .. }) => *value,
+            _ => self.auction.auto_recover,
+        }
+    }
+
+    pub fn bit_value(&self, channel: &str) -> Money {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { bit_value: Some(value), .. }) => *value,
+            _ => self.auction.bit_value.unwrap_or(DEFAULT_BIT_VALUE),
+        }
+    }
+
+    pub fn bits(&self, channel: &str) -> BitsMode {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { bits: Some(value), .. }) => *value,
+            _ => self.auction.bits,
+        }
+    }
+
+    pub fn bid_cooldown(&self, channel: &str) -> Duration {
+        Duration::from_secs(match self.config_channel(channel) {
+            Some(ConfigChannel { bid_cooldown: Some(value), .. }) => *value,
+            _ => self.auction.bid_cooldown.unwrap_or_default(),
+        })
+    }
+
+    pub fn buy_now(&self, channel: &str) -> Option<Money> {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { buy_now: Some(value), .. }) => Some(*value),
+            _ => self.auction.buy_now,
+        }
+    }
+
+    pub fn currency(&self, channel: &str) -> Currency {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { currency: Some(value), .. }) => value.clone(),
+            _ => self.auction.currency.clone(),
+        }
+    }
+
     pub fn duration(&self, channel: &str) -> Duration {
         Duration::from_secs(match self.config_channel(channel) {
             Some(ConfigChannel { duration: Some(value), .. }) => *value,
@@ -227,6 +584,22 @@ impl Config {
         })
     }
 
+    pub fn eligible(&self, channel: &str) -> Eligibility {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { eligible: Some(value), .. }) => value.clone(),
+            _ => self.auction.eligible.clone(),
+        }
+    }
+
+    pub fn enforce_max_raise_on_first(&self, channel: &str) -> bool {
+        match self.config_channel(channel) {
+            Some(ConfigChannel {
+                enforce_max_raise_on_first: Some(value), ..
+            }) => *value,
+            _ => self.auction.enforce_max_raise_on_first,
+        }
+    }
+
     #[cfg(feature = "csv")]
     pub const fn file_csv(&self) -> Option<&PathBuf> {
         self.bot.file_csv.as_ref()
@@ -239,20 +612,76 @@ impl Config {
         })
     }
 
-    pub fn max_raise(&self, channel: &str) -> usize {
+    pub fn helmet_extension(&self, channel: &str) -> Option<Duration> {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { helmet_extension: Some(value), .. }) => Some(*value),
+            _ => self.auction.helmet_extension,
+        }.map(Duration::from_secs)
+    }
+
+    pub fn helmet_max_count(&self, channel: &str) -> Option<usize> {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { helmet_max_count: Some(value), .. }) => Some(*value),
+            _ => self.auction.helmet_max_count,
+        }
+    }
+
+    pub fn helmet_max_total(&self, channel: &str) -> Option<Duration> {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { helmet_max_total: Some(value), .. }) => Some(*value),
+            _ => self.auction.helmet_max_total,
+        }.map(Duration::from_secs)
+    }
+
+    pub fn helmet_policy(&self, channel: &str) -> HelmetPolicy {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { helmet_policy: Some(value), .. }) => *value,
+            _ => self.auction.helmet_policy,
+        }
+    }
+
+    pub fn max_raise(&self, channel: &str) -> Money {
         match self.config_channel(channel) {
             Some(ConfigChannel { max_raise: Some(value), .. }) => *value,
             _ => self.auction.max_raise,
         }
     }
 
-    pub fn min_bid(&self, channel: &str) -> usize {
+    pub fn max_rejection_replies(&self, channel: &str) -> Option<usize> {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { max_rejection_replies: Some(value), .. }) => Some(*value),
+            _ => self.auction.max_rejection_replies,
+        }
+    }
+
+    pub fn max_opening_bid(&self, channel: &str) -> Option<Money> {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { max_opening_bid: Some(value), .. }) => Some(*value),
+            _ => self.auction.max_opening_bid,
+        }
+    }
+
+    pub fn min_bid(&self, channel: &str) -> Money {
         match self.config_channel(channel) {
             Some(ConfigChannel { min_bid: Some(value), .. }) => *value,
             _ => self.auction.min_bid,
         }
     }
 
+    pub fn min_raise(&self, channel: &str) -> Increment {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { min_raise: Some(value), .. }) => *value,
+            _ => self.auction.min_raise.unwrap_or_default(),
+        }
+    }
+
+    pub fn mode(&self, channel: &str) -> AuctionMode {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { mode: Some(value), .. }) => *value,
+            _ => self.auction.mode,
+        }
+    }
+
     pub const fn parse_commands(&self) -> bool {
         self.bot.parse_commands
     }
@@ -261,10 +690,73 @@ impl Config {
         &self.bot.prefix
     }
 
+    pub fn price_interval(&self, channel: &str) -> Duration {
+        Duration::from_secs(match self.config_channel(channel) {
+            Some(ConfigChannel { price_interval: Some(value), .. }) => *value,
+            _ => self.auction.price_interval.unwrap_or(DEFAULT_PRICE_INTERVAL),
+        })
+    }
+
+    pub fn price_step(&self, channel: &str) -> Money {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { price_step: Some(value), .. }) => *value,
+            _ => self.auction.price_step.unwrap_or(DEFAULT_PRICE_STEP),
+        }
+    }
+
     pub const fn reconnect(&self) -> Duration {
         Duration::from_secs(self.bot.reconnect)
     }
 
+    pub fn reserve(&self, channel: &str) -> Option<Money> {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { reserve: Some(value), .. }) => Some(*value),
+            _ => self.auction.reserve,
+        }
+    }
+
+    pub fn settlement(&self, channel: &str) -> Settlement {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { settlement: Some(value), .. }) => *value,
+            _ => self.auction.settlement,
+        }
+    }
+
+    /// Find a preset by name. A preset declared for the channel takes the
+    ///     place of a global preset with the same name.
+    pub fn preset(&self, channel: &str, name: &str) -> Option<&ConfigPreset> {
+        self.presets(channel).into_iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, preset)| preset)
+    }
+
+    /// List the presets available in a channel, ordered by name.
+    pub fn presets(&self, channel: &str) -> Vec<(&str, &ConfigPreset)> {
+        let mut list: Vec<(&str, &ConfigPreset)> = Vec::new();
+        let local = self.config_channel(channel).and_then(|c| c.presets.as_ref());
+
+        for map in [local, self.presets.as_ref()].into_iter().flatten() {
+            for (name, preset) in map {
+                if !list.iter().any(|(key, _)| key.eq_ignore_ascii_case(name)) {
+                    list.push((name, preset));
+                }
+            }
+        }
+
+        list.sort_by_key(|(name, _)| name.to_ascii_lowercase());
+        list
+    }
+
+    pub fn schedule_announce(&self, channel: &str) -> Vec<Duration> {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { schedule_announce: Some(value), .. }) => value.as_slice(),
+            _ => match &self.auction.schedule_announce {
+                Some(value) => value.as_slice(),
+                None => &DEFAULT_SCHEDULE_ANNOUNCE,
+            },
+        }.iter().copied().map(Duration::from_secs).collect()
+    }
+
     pub fn summary(&self, channel: &str) -> bool {
         match self.config_channel(channel) {
             Some(ConfigChannel { summary: Some(value), .. }) => *value,
@@ -272,6 +764,13 @@ impl Config {
         }
     }
 
+    pub fn wallet(&self, channel: &str) -> bool {
+        match self.config_channel(channel) {
+            Some(ConfigChannel { wallet: Some(value), .. }) => *value,
+            _ => self.auction.wallet,
+        }
+    }
+
     pub fn verb(&self, channel: &str) -> &str {
         match self.config_channel(channel) {
             Some(ConfigChannel { verb: Some(value), .. }) => value,
@@ -333,6 +832,7 @@ pub struct ConfigFile {
 
 
 impl ConfigFile {
+    #[allow(clippy::result_large_err)]
     pub fn reload(&mut self) -> Result<(), ConfigOpen> {
         match Config::open(&self.path) {
             ConfigOpen::FileValid(new) => {
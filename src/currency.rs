@@ -0,0 +1,353 @@
+use std::{
+    fmt::{Display, Formatter},
+    iter::Sum,
+    str::FromStr,
+};
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+
+/// An amount of money, stored as a whole number of hundredths, so that it can
+///     be compared and added exactly. This is enough for the minor units of
+///     most currencies. Whole amounts are written without a decimal point.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Money(u64);
+
+impl Money {
+    /// The number of hundredths in one whole unit.
+    const SCALE: u64 = 100;
+    /// The largest number of decimal places that can be represented.
+    pub const DECIMALS: u8 = 2;
+
+    pub const ZERO: Self = Self(0);
+
+    pub const fn whole(amount: u64) -> Self { Self(amount * Self::SCALE) }
+    pub const fn from_cents(cents: u64) -> Self { Self(cents) }
+    pub const fn cents(self) -> u64 { self.0 }
+
+    pub const fn is_whole(self) -> bool { self.0.is_multiple_of(Self::SCALE) }
+    pub const fn is_zero(self) -> bool { self.0 == 0 }
+
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    pub const fn saturating_mul(self, factor: u64) -> Self {
+        Self(self.0.saturating_mul(factor))
+    }
+
+    /// Determine how many times `step` must be added to cover this amount.
+    pub const fn div_ceil(self, step: Self) -> u64 {
+        self.0.div_ceil(step.0)
+    }
+
+    /// Round up to the next multiple of `unit`.
+    pub const fn round_up(self, unit: Self) -> Self {
+        Self(self.div_ceil(unit).saturating_mul(unit.0))
+    }
+
+    /// Take a percentage of this amount, rounding up to the nearest hundredth.
+    pub const fn percent(self, pct: u64) -> Self {
+        Self(self.0.saturating_mul(pct).div_ceil(100))
+    }
+
+    fn as_f64(self) -> f64 { self.0 as f64 / Self::SCALE as f64 }
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (whole, frac) = (self.0 / Self::SCALE, self.0 % Self::SCALE);
+
+        match frac {
+            0 => write!(f, "{}", whole),
+            _ => write!(f, "{}.{:02}", whole, frac),
+        }
+    }
+}
+
+impl FromStr for Money {
+    type Err = ParseMoneyError;
+
+    /// Read an amount with up to two decimal places, like `5`, `2.5` or
+    ///     `0.99`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (whole, frac) = s.split_once('.').unwrap_or((s, ""));
+
+        let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if (whole.is_empty() && frac.is_empty())
+            || !all_digits(whole) || !all_digits(frac)
+        {
+            return Err(ParseMoneyError::Invalid);
+        }
+
+        if usize::from(Self::DECIMALS) < frac.len() {
+            return Err(ParseMoneyError::TooPrecise(Self::DECIMALS));
+        }
+
+        let whole: u64 = match whole {
+            "" => 0,
+            digits => digits.parse().map_err(|_| ParseMoneyError::Invalid)?,
+        };
+        let frac: u64 = format!("{:0<2}", frac).parse()
+            .map_err(|_| ParseMoneyError::Invalid)?;
+
+        whole.checked_mul(Self::SCALE)
+            .and_then(|n| n.checked_add(frac))
+            .map(Self)
+            .ok_or(ParseMoneyError::Invalid)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Self::saturating_add)
+    }
+}
+
+/// Written as an integer when whole, or as a float otherwise.
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.is_whole() {
+            true => serializer.serialize_u64(self.0 / Self::SCALE),
+            false => serializer.serialize_f64(self.as_f64()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MoneyVisitor;
+
+        impl<'de> Visitor<'de> for MoneyVisitor {
+            type Value = Money;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a non-negative amount of money")
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<Money, E> {
+                v.checked_mul(Money::SCALE).map(Money)
+                    .ok_or_else(|| E::custom("amount is too large"))
+            }
+
+            fn visit_i64<E: Error>(self, v: i64) -> Result<Money, E> {
+                match u64::try_from(v) {
+                    Ok(v) => self.visit_u64(v),
+                    Err(_) => Err(E::custom("amount may not be negative")),
+                }
+            }
+
+            fn visit_f64<E: Error>(self, v: f64) -> Result<Money, E> {
+                match (v * Money::SCALE as f64).round() {
+                    cents if cents.is_finite() && 0.0 <= cents => {
+                        Ok(Money(cents as u64))
+                    }
+                    _ => Err(E::custom("amount may not be negative")),
+                }
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Money, E> {
+                v.trim().parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(MoneyVisitor)
+    }
+}
+
+
+/// The reason an amount of money could not be read.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseMoneyError {
+    /// The text is not a non-negative number.
+    Invalid,
+    /// The amount has more decimal places than the currency allows.
+    TooPrecise(u8),
+}
+
+impl Display for ParseMoneyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid => f.write_str("invalid amount"),
+            Self::TooPrecise(0) => f.write_str("amount must be a whole number"),
+            Self::TooPrecise(n) => write!(f, "amount may have at most {} decimal places", n),
+        }
+    }
+}
+
+impl std::error::Error for ParseMoneyError {}
+
+
+/// Where the symbol of a [`Currency`] is written, relative to the amount.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Placement {
+    /// The symbol is written before the amount, as in `$5`.
+    #[default]
+    Before,
+    /// The symbol is written after the amount, as in `5€`.
+    After,
+}
+
+
+/// The unit in which bids are placed. May be specified in a Configuration file
+///     as an ISO code for a known currency, like `"GBP"`, or as a table with
+///     its own symbol.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "CurrencyRaw", into = "CurrencyRaw")]
+pub struct Currency {
+    /// The symbol written alongside amounts in chat, like `$`.
+    pub symbol: String,
+    /// The ISO 4217 code of the currency, like `USD`.
+    pub code: String,
+    pub placement: Placement,
+    /// The number of digits after the decimal point in the smallest amount
+    ///     of the currency. No more than [`Money::DECIMALS`].
+    pub decimals: u8,
+}
+
+impl Currency {
+    fn known(code: &str) -> Option<Self> {
+        let (symbol, placement, decimals) = match code {
+            "USD" | "CAD" | "AUD" | "NZD" => ("$", Placement::Before, 2),
+            "EUR" => ("€", Placement::Before, 2),
+            "GBP" => ("£", Placement::Before, 2),
+            "JPY" => ("¥", Placement::Before, 0),
+            _ => return None,
+        };
+
+        Some(Self {
+            symbol: symbol.to_owned(),
+            code: code.to_owned(),
+            placement,
+            decimals,
+        })
+    }
+
+    /// Look up a known currency by its ISO code.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::known(&code.trim().to_ascii_uppercase())
+    }
+
+    /// The smallest amount of this currency, like one cent or one yen.
+    pub fn unit(&self) -> Money {
+        Money::from_cents(10u64.pow(u32::from(Money::DECIMALS - self.decimals)))
+    }
+
+    /// Write an amount of this currency, with its symbol.
+    pub fn format(&self, amount: Money) -> String {
+        match self.placement {
+            Placement::Before => format!("{}{}", self.symbol, amount),
+            Placement::After => format!("{}{}", amount, self.symbol),
+        }
+    }
+
+    /// Read an amount of this currency, as written by a user. The symbol and
+    ///     the ISO code are both optional. The amount may not be more precise
+    ///     than the minor unit of the currency.
+    pub fn parse(&self, text: &str) -> Result<Money, ParseMoneyError> {
+        self.check(self.strip(text).parse()?)
+    }
+
+    /// Remove the symbol and the ISO code of this currency from either end of
+    ///     some text, if they are present.
+    pub fn strip<'t>(&self, text: &'t str) -> &'t str {
+        let mut text: &str = text.trim();
+
+        for affix in [self.symbol.as_str(), self.code.as_str()] {
+            if affix.is_empty() { continue; }
+
+            if let Some(stripped) = strip_prefix_ignore_case(text, affix) {
+                text = stripped.trim_start();
+            }
+
+            if let Some(stripped) = strip_suffix_ignore_case(text, affix) {
+                text = stripped.trim_end();
+            }
+        }
+
+        text
+    }
+
+    /// Ensure that an amount is not more precise than the minor unit of this
+    ///     currency.
+    pub fn check(&self, amount: Money) -> Result<Money, ParseMoneyError> {
+        match amount.cents().is_multiple_of(self.unit().cents()) {
+            true => Ok(amount),
+            false => Err(ParseMoneyError::TooPrecise(self.decimals)),
+        }
+    }
+}
+
+impl Default for Currency {
+    fn default() -> Self { Self::known("USD").unwrap() }
+}
+
+
+fn strip_prefix_ignore_case<'s>(text: &'s str, prefix: &str) -> Option<&'s str> {
+    let head = text.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix).then(|| &text[prefix.len()..])
+}
+
+
+fn strip_suffix_ignore_case<'s>(text: &'s str, suffix: &str) -> Option<&'s str> {
+    let split = text.len().checked_sub(suffix.len())?;
+    let tail = text.get(split..)?;
+    tail.eq_ignore_ascii_case(suffix).then(|| &text[..split])
+}
+
+
+/// Decimal places of a currency given as a table, if not otherwise specified.
+const fn default_decimals() -> u8 { Money::DECIMALS }
+
+
+/// Representation of a [`Currency`] in a Configuration file.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum CurrencyRaw {
+    Code(String),
+    Table {
+        symbol: String,
+        code: String,
+        #[serde(default)]
+        placement: Placement,
+        #[serde(default = "default_decimals")]
+        decimals: u8,
+    },
+}
+
+impl TryFrom<CurrencyRaw> for Currency {
+    type Error = String;
+
+    fn try_from(raw: CurrencyRaw) -> Result<Self, Self::Error> {
+        match raw {
+            CurrencyRaw::Code(code) => Self::from_code(&code)
+                .ok_or_else(|| format!("unknown currency code: {:?}", code)),
+            CurrencyRaw::Table { decimals, .. } if Money::DECIMALS < decimals => {
+                Err(format!(
+                    "currency may have at most {} decimal places",
+                    Money::DECIMALS,
+                ))
+            }
+            CurrencyRaw::Table { symbol, code, placement, decimals } => Ok(Self {
+                symbol,
+                code: code.to_ascii_uppercase(),
+                placement,
+                decimals,
+            }),
+        }
+    }
+}
+
+impl From<Currency> for CurrencyRaw {
+    fn from(currency: Currency) -> Self {
+        let Currency { symbol, code, placement, decimals } = currency;
+        Self::Table { symbol, code, placement, decimals }
+    }
+}
@@ -6,6 +6,7 @@ pub mod macros;
 
 pub mod bot;
 pub mod config;
+pub mod currency;
 pub mod saving;
 
 use std::sync::atomic::{AtomicBool, Ordering::SeqCst};
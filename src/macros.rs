@@ -1,84 +1,3 @@
-#[macro_export]
-macro_rules! usd {
-    () => { "USD" };
-    ($amount:literal) => { concat!("$", $amount) };
-    ($amount:expr) => { format_args!(usd!("{}"), $amount) };
-}
-
-
-cfg_if::cfg_if! {
-    if #[cfg(not(any(
-        feature = "currency_cad",
-        feature = "currency_eur",
-        feature = "currency_gbp",
-        feature = "currency_jpy",
-    )))] {
-        #[macro_export]
-        macro_rules! money {($($tt:tt)*) => {$crate::usd!($($tt)*)}}
-    } else if #[cfg(all(
-        feature = "currency_cad",
-        not(any(
-            feature = "currency_eur",
-            feature = "currency_gbp",
-            feature = "currency_jpy",
-        )),
-    ))] {
-        #[macro_export]
-        macro_rules! money {
-            () => { "CAD" };
-            ($amount:literal) => { concat!("$", $amount) };
-            ($amount:expr) => { format_args!(money!("{}"), $amount) };
-        }
-    } else if #[cfg(all(
-        feature = "currency_eur",
-        not(any(
-            feature = "currency_cad",
-            feature = "currency_gbp",
-            feature = "currency_jpy",
-        )),
-    ))] {
-        #[macro_export]
-        macro_rules! money {
-            () => { "EUR" };
-            ($amount:literal) => { concat!("€", $amount) };
-            ($amount:expr) => { format_args!(money!("{}"), $amount) };
-        }
-    } else if #[cfg(all(
-        feature = "currency_gbp",
-        not(any(
-            feature = "currency_cad",
-            feature = "currency_eur",
-            feature = "currency_jpy",
-        )),
-    ))] {
-        #[macro_export]
-        macro_rules! money {
-            () => { "GBP" };
-            ($amount:literal) => { concat!("£", $amount) };
-            ($amount:expr) => { format_args!(money!("{}"), $amount) };
-        }
-    } else if #[cfg(all(
-        feature = "currency_jpy",
-        not(any(
-            feature = "currency_cad",
-            feature = "currency_eur",
-            feature = "currency_gbp",
-        )),
-    ))] {
-        #[macro_export]
-        macro_rules! money {
-            () => { "JPY" };
-            ($amount:literal) => { concat!("¥", $amount) };
-            ($amount:expr) => { format_args!(money!("{}"), $amount) };
-        }
-    } else {
-        #[macro_export]
-        macro_rules! money {($($tt:tt)*) => {$crate::usd!($($tt)*)}}
-        compile_error!("Only one Currency feature may be enabled.");
-    }
-}
-
-
 #[cfg(not(feature = "chrono"))]
 #[macro_export]
 macro_rules! _msg {
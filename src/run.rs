@@ -40,7 +40,7 @@ pub fn cfg_check(cfg_path: Option<PathBuf>) -> ! {
 
 pub fn cfg_make(cfg_path: Option<PathBuf>) -> ! {
     match Config::find(cfg_path).path() {
-        Some(path) => match Config::create(&path, true) {
+        Some(path) => match Config::create(path, true) {
             Ok(..) => {
                 println!("Default Config file created: {}", path.display());
                 exit(0);
@@ -4,6 +4,7 @@ use std::{fs::File, path::Path};
 #[cfg(feature = "chrono")]
 use chrono::{DateTime, Utc};
 use csv::{QuoteStyle, ReaderBuilder, Terminator, WriterBuilder};
+use crate::{bot::auction::Closure, currency::Money};
 use super::{AuctionFinished, Winner};
 
 
@@ -21,7 +22,7 @@ pub struct AuctionRecord {
     pub closed: (),
 
     pub duration_seconds: u64,
-    pub winning_bid: Option<usize>,
+    pub winning_bid: Option<Money>,
     pub winner: Option<String>,
     pub prize: Option<String>,
     pub note: Option<String>,
@@ -31,6 +32,7 @@ impl AuctionRecord {
     //  NOTE: This method exists to ensure at compile time that all fields are
     //      present, regardless of what Features are enabled. No Feature checks
     //      should be made here.
+    #[allow(clippy::no_effect)]
     fn _drop(self) {
         self.opened;
         self.closed;
@@ -45,12 +47,12 @@ impl AuctionRecord {
 impl From<&AuctionFinished> for AuctionRecord {
     fn from(auction: &AuctionFinished) -> Self {
         let winner: Option<String>;
-        let winning_bid: Option<usize>;
+        let winning_bid: Option<Money>;
 
         match &auction.winner {
-            Some(Winner { name, amount, .. }) => {
+            Some(Winner { name, price_paid, .. }) => {
                 winner = Some(name.clone());
-                winning_bid = Some(*amount);
+                winning_bid = Some(*price_paid);
             }
             None => {
                 winner = None;
@@ -73,12 +75,45 @@ impl From<&AuctionFinished> for AuctionRecord {
             winning_bid,
             winner,
             prize: auction.prize.clone(),
-            note: None,
+            note: auction_note(auction),
         }
     }
 }
 
 
+/// Describe anything unusual about the outcome of an Auction.
+fn auction_note(auction: &AuctionFinished) -> Option<String> {
+    let outcome: Option<String> = match auction.closed_by {
+        _ if !auction.disqualified.is_empty() => Some(format!(
+            "Reassigned; Disqualified {}",
+            auction.disqualified.join(", "),
+        )),
+        _ if !auction.reserve_met && !auction.bids.is_empty() => {
+            Some(String::from("Reserve not met"))
+        }
+        Closure::BuyNow => Some(String::from("Buy it now")),
+        _ => match &auction.winner {
+            Some(Winner { bits: Some(bits), .. }) => Some(format!("{} Bits", bits)),
+            _ => None,
+        },
+    };
+
+    let shared: Option<String> = match &auction.winner {
+        _ if auction.channels.is_empty() => None,
+        Some(Winner { channel: Some(channel), .. }) => Some(format!(
+            "Shared by {}; Won in {}",
+            auction.channels.join(", "), channel,
+        )),
+        _ => Some(format!("Shared by {}", auction.channels.join(", "))),
+    };
+
+    match (outcome, shared) {
+        (Some(outcome), Some(shared)) => Some(format!("{}. {}", outcome, shared)),
+        (outcome, shared) => outcome.or(shared),
+    }
+}
+
+
 impl AuctionFinished {
     fn csv_reader(headers: bool) -> ReaderBuilder {
         let mut rb = ReaderBuilder::new();
@@ -102,7 +137,7 @@ impl AuctionFinished {
     pub fn save_csv(&self, path: &Path) -> std::io::Result<()> {
         let mut csv = if path.exists() {
             if cfg!(feature = "csv_validate") {
-                let mut read = Self::csv_reader(true).from_path(&path)?;
+                let mut read = Self::csv_reader(true).from_path(path)?;
                 let mut iter = read.deserialize::<AuctionRecord>();
 
                 if let Some(record) = iter.next() {
@@ -113,9 +148,9 @@ impl AuctionFinished {
             Self::csv_writer(false)
                 .from_writer(File::options()
                     .append(true)
-                    .open(&path)?)
+                    .open(path)?)
         } else {
-            Self::csv_writer(true).from_path(&path)?
+            Self::csv_writer(true).from_path(path)?
         };
 
         csv.serialize(self.to_record())?;
@@ -0,0 +1,119 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{ErrorKind, Read, Write},
+    path::PathBuf,
+};
+use crate::currency::Money;
+
+
+/// The balance of every viewer who may bid from a wallet, kept in the data
+///     directory between runs of the bot. Viewers are known by their login,
+///     which is stored in lowercase.
+#[derive(Default, Deserialize, Serialize)]
+pub struct Ledger {
+    /// The Auction ID to which this Ledger belongs. A Ledger that does not
+    ///     belong to one is only kept in memory.
+    #[serde(skip)]
+    id: Option<String>,
+    #[serde(default)]
+    balances: BTreeMap<String, Money>,
+}
+
+impl Ledger {
+    const FILE_EXT: &'static str = "toml";
+
+    fn path(id: &str) -> Option<PathBuf> {
+        let mut path = crate::dirs()?.data_dir().to_owned();
+
+        path.push(format!(
+            "ledger-{}.{}",
+            id.trim_start_matches('#').to_ascii_lowercase(),
+            Self::FILE_EXT,
+        ));
+
+        Some(path)
+    }
+
+    /// Read the Ledger of an Auction ID. A Ledger that has never been saved
+    ///     starts out empty. A Ledger that cannot be read is an error, rather
+    ///     than empty, so that it is never overwritten by mistake.
+    pub fn load(id: &str) -> std::io::Result<Self> {
+        let path: PathBuf = Self::path(id).ok_or(ErrorKind::NotFound)?;
+        let mut data: String = String::new();
+
+        match File::open(&path) {
+            Ok(mut file) => file.read_to_string(&mut data)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Ok(Self { id: Some(id.to_owned()), ..Self::default() });
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut ledger: Self = toml::from_str(&data)
+            .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+        ledger.id = Some(id.to_owned());
+
+        info!("Loaded Ledger from file: {}", path.display());
+        Ok(ledger)
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let id: &str = match &self.id {
+            Some(id) => id,
+            None => return Ok(()),
+        };
+
+        match Self::path(id) {
+            Some(path) => match toml::to_vec(self) {
+                Ok(data) => {
+                    if let Some(dir) = path.parent() {
+                        std::fs::create_dir_all(dir)?;
+                    }
+
+                    //  The file is replaced in one step, so that a crash while
+                    //      writing cannot lose every balance.
+                    let temp: PathBuf = path.with_extension("tmp");
+                    File::create(&temp)?.write_all(&data)?;
+                    std::fs::rename(&temp, &path)?;
+                }
+                Err(e) => warn!("Failed to serialize Ledger: {}", e),
+            }
+            None => warn!("Failed to find data directory."),
+        }
+
+        Ok(())
+    }
+
+    /// Change the balance of a viewer, and save the Ledger immediately.
+    ///     Returns the new balance.
+    fn update(&mut self, name: &str, f: impl FnOnce(Money) -> Money) -> Money {
+        let key: String = name.trim_start_matches('@').to_lowercase();
+        let balance: &mut Money = self.balances.entry(key).or_default();
+        *balance = f(*balance);
+
+        let new: Money = *balance;
+
+        if let Err(e) = self.save() {
+            warn!("Failed to save Ledger: {}", e);
+        }
+
+        new
+    }
+
+    pub fn balance(&self, name: &str) -> Money {
+        let key: String = name.trim_start_matches('@').to_lowercase();
+        self.balances.get(&key).copied().unwrap_or_default()
+    }
+
+    /// Add to the balance of a viewer. Returns the new balance.
+    pub fn grant(&mut self, name: &str, amount: Money) -> Money {
+        self.update(name, |balance| balance.saturating_add(amount))
+    }
+
+    /// Take from the balance of a viewer, down to no less than zero. Returns
+    ///     the new balance.
+    pub fn revoke(&mut self, name: &str, amount: Money) -> Money {
+        self.update(name, |balance| balance.saturating_sub(amount))
+    }
+}
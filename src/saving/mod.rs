@@ -1,28 +1,94 @@
 mod csv_record;
+mod ledger;
+mod snapshot;
 
 use std::{fmt::Display, fs::File, io::Write};
 #[cfg(feature = "chrono")]
 use chrono::{DateTime, Duration, SubsecRound, Utc};
 use heck::SnakeCase;
-use crate::bot::auction::{Auction, Bid, Winner};
+use crate::{
+    bot::auction::{
+        Adjustment, Auction, AuctionMode, AuctionOptions, Bid, BitsMode,
+        Closure, Eligibility, Extension, HelmetPolicy, Increment, Pause,
+        PricePoint, Settlement, Winner,
+    },
+    currency::Money,
+};
+pub use ledger::Ledger;
+pub use snapshot::{Snapshot, SnapshotWriter};
+
+
+/// Parameters and price history of a Dutch Auction.
+#[derive(Deserialize, Serialize)]
+#[cfg_attr(feature = "summaries_pascal", serde(rename_all = "PascalCase"))]
+pub struct DutchPrices {
+    pub start_price: Money,
+    pub floor_price: Money,
+    pub price_step: Money,
+    pub price_interval: u64,
+
+    #[serde(rename = "PRICE", skip_serializing_if = "Vec::is_empty")]
+    pub prices: Vec<PricePoint>,
+}
 
 
 #[derive(Deserialize, Serialize)]
 #[cfg_attr(feature = "summaries_pascal", serde(rename_all = "PascalCase"))]
 pub struct AuctionFinished {
     pub prize: Option<String>,
-    pub minimum_bid: usize,
-    pub raise_limit: usize,
+    /// Channels that ran the Auction together, if there were more than one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channels: Vec<String>,
+    /// ISO code of the currency in which all amounts are given.
+    pub currency: String,
+    pub mode: AuctionMode,
+    pub settlement: Settlement,
+    pub minimum_bid: Money,
+    pub raise_limit: Money,
+    pub raise_minimum: Increment,
+    #[serde(default, skip_serializing_if = "Eligibility::is_open")]
+    pub eligible: Eligibility,
+    pub enforce_max_raise_on_first: bool,
+    pub maximum_opening_bid: Option<Money>,
+    pub reserve: Option<Money>,
+    pub reserve_met: bool,
+    pub buy_now: Option<Money>,
     pub duration: u64,
     pub helmet: u64,
+    #[serde(default)]
+    pub helmet_policy: HelmetPolicy,
+    pub helmet_extension: Option<u64>,
+    pub helmet_max_total: Option<u64>,
+    pub helmet_max_count: Option<usize>,
+    #[serde(default, skip_serializing_if = "BitsMode::is_off")]
+    pub bits: BitsMode,
+    /// Value of a single Bit, if Bits were accepted with bids.
+    pub bit_value: Option<Money>,
 
     #[cfg(feature = "chrono")]
     pub opened: DateTime<Utc>,
     #[cfg(feature = "chrono")]
     pub closed: DateTime<Utc>,
+    pub closed_by: Closure,
+    pub bids_rejected: usize,
+    pub bids_throttled: usize,
+    pub replies_suppressed: usize,
+    /// Bits cheered with bids that were refused, if Bits were accepted.
+    pub bits_refused: Option<u64>,
+    /// Winners who failed to pay, in the order they were disqualified.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disqualified: Vec<String>,
 
     #[serde(rename = "WINNER")]
     pub winner: Option<Winner>,
+    #[serde(rename = "DUTCH")]
+    pub dutch: Option<DutchPrices>,
+    #[serde(rename = "PAUSE", skip_serializing_if = "Vec::is_empty")]
+    pub pauses: Vec<Pause>,
+    #[serde(rename = "ADJUSTMENT", skip_serializing_if = "Vec::is_empty")]
+    pub adjustments: Vec<Adjustment>,
+    #[serde(default, rename = "EXTENSION", skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<Extension>,
     #[serde(rename = "BID", skip_serializing_if = "Vec::is_empty")]
     pub bids: Vec<Bid>,
 }
@@ -96,18 +162,38 @@ impl AuctionFinished {
 impl From<Auction> for AuctionFinished {
     fn from(auction: Auction) -> Self {
         let winner = auction.winner();
+        let reserve_met = auction.reserve_met();
         #[allow(unused_variables)]
         let Auction {
-            bids, prize,
-            duration, helmet,
-            max_raise, min_bid,
-            time_begin, time_close,
+            bids, prize, options,
+            prices, closure, pauses, adjustments, extensions, disqualified,
+            rejected, throttled, muted, bits_refused, channels,
+            clock, time_begin, time_close,
+            ..
         } = auction;
+        let dutch = match options.mode {
+            AuctionMode::Dutch => Some(DutchPrices {
+                start_price: options.price_start(),
+                floor_price: options.price_floor(),
+                price_step: options.price_step,
+                price_interval: options.price_interval.as_secs(),
+                prices,
+            }),
+            _ => None,
+        };
+        let AuctionOptions {
+            currency, mode, settlement, duration, helmet,
+            helmet_policy, helmet_extension, helmet_max_total, helmet_max_count,
+            bits, bit_value, max_raise, min_bid, min_raise, eligible,
+            enforce_max_raise_on_first, max_opening_bid,
+            reserve, buy_now,
+            ..
+        } = options;
 
         #[cfg(feature = "chrono")]
         let (opened, closed) = {
-            let now = std::time::Instant::now();
-            let now_utc: DateTime<Utc> = Utc::now();
+            let now = clock.now();
+            let now_utc: DateTime<Utc> = clock.utc();
 
             let since_begin = Duration::from_std(now - time_begin)
                 .unwrap_or_else(|_| Duration::zero());
@@ -122,17 +208,44 @@ impl From<Auction> for AuctionFinished {
 
         Self {
             prize,
+            channels,
+            currency: currency.code,
+            mode,
+            settlement,
             minimum_bid: min_bid,
             raise_limit: max_raise,
+            raise_minimum: min_raise,
+            eligible,
+            enforce_max_raise_on_first,
+            maximum_opening_bid: max_opening_bid,
+            reserve,
+            reserve_met,
+            buy_now,
             duration: duration.as_secs(),
             helmet: helmet.as_secs(),
+            helmet_policy,
+            helmet_extension: helmet_extension.map(|d| d.as_secs()),
+            helmet_max_total: helmet_max_total.map(|d| d.as_secs()),
+            helmet_max_count,
+            bits,
+            bit_value: (!bits.is_off()).then_some(bit_value),
 
             #[cfg(feature = "chrono")]
             opened,
             #[cfg(feature = "chrono")]
             closed,
+            closed_by: closure.unwrap_or_default(),
+            bids_rejected: rejected,
+            bids_throttled: throttled,
+            replies_suppressed: muted,
+            bits_refused: (!bits.is_off()).then_some(bits_refused),
+            disqualified,
 
             winner,
+            dutch,
+            pauses,
+            adjustments,
+            extensions,
             bids,
         }
     }
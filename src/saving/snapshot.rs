@@ -0,0 +1,178 @@
+use std::{
+    fs::File,
+    io::{ErrorKind, Read, Write},
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
+use crate::bot::{auction::{Auction, PauseReason}, clock::Clock};
+
+
+/// The state of a running Auction, kept in the data directory so that it can
+///     be recovered if the bot stops without ending it.
+#[derive(Deserialize, Serialize)]
+pub struct Snapshot {
+    /// The moment at which the Snapshot was written.
+    pub saved: SystemTime,
+    /// The moment at which the Auction opened.
+    pub opened: SystemTime,
+    /// The moment at which the Auction was due to close, as of the Snapshot.
+    pub closes: SystemTime,
+    /// The time that remained in the Auction, as of the Snapshot.
+    pub remaining: Duration,
+    /// Whether an operator had paused the Auction.
+    pub paused: bool,
+
+    pub auction: Auction,
+}
+
+impl Snapshot {
+    const FILE_EXT: &'static str = "toml";
+
+    fn path(id: &str) -> Option<PathBuf> {
+        let mut path = crate::dirs()?.data_dir().to_owned();
+
+        path.push(format!(
+            "snapshot-{}.{}",
+            id.trim_start_matches('#').to_ascii_lowercase(),
+            Self::FILE_EXT,
+        ));
+
+        Some(path)
+    }
+
+    pub fn new(auction: &Auction) -> Self {
+        let now: SystemTime = auction.clock.system();
+        let remaining: Duration = auction.time_left().unwrap_or_default();
+
+        Self {
+            saved: now,
+            opened: now - auction.clock.now().saturating_duration_since(auction.time_begin),
+            closes: now + remaining,
+            remaining,
+            paused: auction.paused() == Some(PauseReason::Operator),
+            auction: auction.clone(),
+        }
+    }
+
+    /// Read the Snapshot of an Auction ID, if there is one.
+    pub fn load(id: &str) -> std::io::Result<Option<Self>> {
+        let path: PathBuf = Self::path(id).ok_or(ErrorKind::NotFound)?;
+        let mut data: String = String::new();
+
+        match File::open(&path) {
+            Ok(mut file) => file.read_to_string(&mut data)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        toml::from_str(&data)
+            .map(Some)
+            .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, id: &str) -> std::io::Result<()> {
+        let path: PathBuf = Self::path(id).ok_or(ErrorKind::NotFound)?;
+        let data: String = toml::Value::try_from(self)
+            .and_then(|value| toml::to_string(&value))
+            .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        //  The file is replaced in one step, so that a crash while writing
+        //      cannot leave half of a Snapshot behind.
+        let temp: PathBuf = path.with_extension("tmp");
+        let mut file: File = File::create(&temp)?;
+        file.write_all(data.as_bytes())?;
+        file.sync_all()?;
+        drop(file);
+
+        std::fs::rename(&temp, &path)
+    }
+
+    pub fn remove(id: &str) -> std::io::Result<()> {
+        match Self::path(id).map(std::fs::remove_file) {
+            Some(Err(e)) if e.kind() != ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    /// Rebuild the Auction as it was when the Snapshot was written, on the
+    ///     given Clock. The time the bot spent stopped is given back, and
+    ///     recorded as a pause.
+    pub fn restore(self, clock: Arc<dyn Clock>) -> Auction {
+        let now: SystemTime = clock.system();
+        let downtime: Duration = now.duration_since(self.saved).unwrap_or_default();
+        let mut auction: Auction = self.auction;
+        auction.clock = clock;
+
+        auction.recover(
+            now.duration_since(self.opened).unwrap_or_default(),
+            self.remaining,
+            downtime,
+            self.paused,
+        );
+
+        auction
+    }
+}
+
+
+/// Keeps the Snapshot of a running Auction up to date, writing it whenever the
+///     Auction changes, and regularly in between, so that the moment the bot
+///     stopped is known closely.
+pub struct SnapshotWriter {
+    id: String,
+    /// The Auction as of the last Snapshot, without its timing.
+    last: Option<String>,
+    written: Instant,
+}
+
+impl SnapshotWriter {
+    /// Longest time between Snapshots of an Auction that has not changed.
+    const INTERVAL: Duration = Duration::from_secs(10);
+
+    pub fn new(id: String) -> Self {
+        Self { id, last: None, written: Instant::now() }
+    }
+
+    /// Write a Snapshot of the Auction if it is due, or remove the Snapshot if
+    ///     the Auction has ended. A Snapshot left behind by an earlier run is
+    ///     not removed until this writer has replaced it.
+    pub fn update(&mut self, auction: Option<&Auction>) {
+        let auction: &Auction = match auction {
+            Some(auction) => auction,
+            None => {
+                if self.last.take().is_some() {
+                    if let Err(e) = Snapshot::remove(&self.id) {
+                        warn!("Failed to remove Snapshot: {}", e);
+                    }
+                }
+
+                return;
+            }
+        };
+
+        let current: String = match toml::Value::try_from(auction) {
+            Ok(value) => value.to_string(),
+            Err(e) => {
+                warn!("Failed to serialize Snapshot: {}", e);
+                return;
+            }
+        };
+
+        if self.last.as_ref() == Some(&current) && self.written.elapsed() < Self::INTERVAL {
+            return;
+        }
+
+        match Snapshot::new(auction).save(&self.id) {
+            Ok(()) => {
+                self.last = Some(current);
+                self.written = Instant::now();
+            }
+            Err(e) => warn!("Failed to save Snapshot: {}", e),
+        }
+    }
+}
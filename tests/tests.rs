@@ -3,17 +3,57 @@ use k2o::*;
 
 #[test]
 fn test_currency() {
-    const FIVE: usize = 5;
+    use currency::{Currency, Money, Placement};
 
-    let _args: std::fmt::Arguments = money!(FIVE);
-    let five: String = format!("{}", money!(FIVE));
-    let ltrl: &'static str = money!(5);
+    let usd = Currency::default();
+    let gbp = Currency::from_code("gbp").unwrap();
+    let jpy = Currency::from_code("JPY").unwrap();
+    let sek = Currency {
+        symbol: String::from(" kr"),
+        code: String::from("SEK"),
+        placement: Placement::After,
+        decimals: 2,
+    };
 
-    dbg!(&five);
-    dbg!(&ltrl);
+    let five = Money::whole(5);
+    let half = Money::from_cents(250);
 
-    assert_eq!(five, "$5");
-    assert_eq!(ltrl, "$5");
+    assert_eq!(usd.format(five), "$5");
+    assert_eq!(usd.format(half), "$2.50");
+    assert_eq!(gbp.format(five), "£5");
+    assert_eq!(sek.format(five), "5 kr");
+    assert_eq!(jpy.format(five), "¥5");
+
+    assert_eq!(usd.parse("$5"), Ok(five));
+    assert_eq!(usd.parse("5"), Ok(five));
+    assert_eq!(usd.parse("5usd"), Ok(five));
+    assert_eq!(usd.parse("5.00"), Ok(five));
+    assert_eq!(usd.parse("2.5"), Ok(half));
+    assert_eq!(gbp.parse("£2.50"), Ok(half));
+    assert_eq!(sek.parse("5 kr"), Ok(five));
+    assert_eq!(sek.parse("5SEK"), Ok(five));
+    assert_eq!(jpy.parse("¥5"), Ok(five));
+
+    assert!(gbp.parse("$5").is_err());
+    assert!(usd.parse("-5").is_err());
+    assert!(usd.parse("2.505").is_err());
+    assert!(usd.parse(".").is_err());
+    assert!(jpy.parse("2.5").is_err());
+    assert!(Currency::from_code("XYZ").is_none());
+
+    //  A currency written as a table has two decimal places unless it says
+    //      otherwise.
+    let table = |text: &str| toml::from_str::<toml::Value>(&format!("currency = {}", text))
+        .unwrap()["currency"]
+        .clone()
+        .try_into::<Currency>();
+
+    assert_eq!(table(r#"{ symbol = " kr", code = "sek", placement = "after" }"#).unwrap(), sek);
+    assert_eq!(
+        table(r#"{ symbol = "P", code = "PTS", decimals = 0 }"#).unwrap().unit(),
+        Money::whole(1),
+    );
+    assert!(table(r#"{ symbol = "P", code = "PTS", decimals = 3 }"#).is_err());
 }
 
 
@@ -49,3 +89,1136 @@ fn test_splitter() {
         eprintln!();
     }
 }
+
+
+#[test]
+fn test_bid_amount() {
+    use bot::BidAmount;
+    use currency::{Currency, Money, ParseMoneyError};
+
+    let usd = Currency::default();
+    let jpy = Currency::from_code("JPY").unwrap();
+    let parse = |text: &str| BidAmount::parse(text, &usd);
+
+    assert_eq!(parse("50"), Ok(BidAmount::Exact(Money::whole(50))));
+    assert_eq!(parse("$50"), Ok(BidAmount::Exact(Money::whole(50))));
+    assert_eq!(parse("1.5k"), Ok(BidAmount::Exact(Money::whole(1_500))));
+    assert_eq!(parse("$2K"), Ok(BidAmount::Exact(Money::whole(2_000))));
+    assert_eq!(parse("1m"), Ok(BidAmount::Exact(Money::whole(1_000_000))));
+    assert_eq!(parse("$1,200"), Ok(BidAmount::Exact(Money::whole(1_200))));
+    assert_eq!(parse("1,234,567.89"), Ok(BidAmount::Exact(Money::from_cents(123_456_789))));
+    assert_eq!(parse("+50"), Ok(BidAmount::Raise(Money::whole(50))));
+    assert_eq!(parse("+$2.50"), Ok(BidAmount::Raise(Money::from_cents(250))));
+    assert_eq!(parse("min"), Ok(BidAmount::Minimum));
+    assert_eq!(parse("MIN"), Ok(BidAmount::Minimum));
+
+    assert_eq!(parse("1,5"), Err(ParseMoneyError::Invalid));
+    assert_eq!(parse("12,00"), Err(ParseMoneyError::Invalid));
+    assert_eq!(parse("1,2000"), Err(ParseMoneyError::Invalid));
+    assert_eq!(parse(",200"), Err(ParseMoneyError::Invalid));
+    assert_eq!(parse("k"), Err(ParseMoneyError::Invalid));
+    assert_eq!(parse("++5"), Err(ParseMoneyError::Invalid));
+    assert_eq!(parse("-5"), Err(ParseMoneyError::Invalid));
+    assert_eq!(parse("1.234"), Err(ParseMoneyError::TooPrecise(2)));
+    assert_eq!(BidAmount::parse("1.5", &jpy), Err(ParseMoneyError::TooPrecise(0)));
+    assert_eq!(BidAmount::parse("1.5k", &jpy), Ok(BidAmount::Exact(Money::whole(1_500))));
+
+    let current = Some(Money::whole(100));
+    let next = Money::whole(105);
+
+    assert_eq!(BidAmount::Exact(Money::whole(120)).resolve(current, next), Money::whole(120));
+    assert_eq!(BidAmount::Raise(Money::whole(50)).resolve(current, next), Money::whole(150));
+    assert_eq!(BidAmount::Raise(Money::whole(50)).resolve(None, next), Money::whole(50));
+    assert_eq!(BidAmount::Minimum.resolve(current, next), next);
+}
+
+
+/// Create an Auction with the default options, changed as needed, on a Clock
+///     that only moves when it is advanced.
+fn auction_manual(
+    edit: impl FnOnce(&mut bot::auction::AuctionOptions),
+) -> (std::sync::Arc<bot::clock::ManualClock>, bot::auction::Auction) {
+    use std::sync::Arc;
+    use bot::{auction::Auction, clock::ManualClock};
+
+    let config: Config = toml::from_str(include_str!("../src/cfg_default.toml")).unwrap();
+    let mut options = config.auction_options("");
+    edit(&mut options);
+
+    let clock = Arc::new(ManualClock::new());
+    let auction = Auction::with_clock(options, None, clock.clone());
+
+    (clock, auction)
+}
+
+
+#[test]
+fn test_helmet() {
+    use std::time::Duration;
+    use bot::auction::{Auction, BidResult, HelmetPolicy};
+    use currency::Money;
+
+    let secs = |auction: &Auction| auction.remaining().unwrap().as_secs();
+    let bid = |auction: &mut Auction, name: &str, amount: u64| {
+        let result = auction.bid(name, Money::whole(amount), "");
+        assert!(!result.is_rejection());
+        matches!(result, BidResult::Ok { .. })
+    };
+
+    //  Extend: The Helmet value is added to the timer.
+    let (clock, mut auction) = auction_manual(|opt| {
+        opt.duration = Duration::from_secs(180);
+        opt.helmet = Duration::from_secs(15);
+        opt.helmet_policy = HelmetPolicy::Extend;
+    });
+
+    assert_eq!(secs(&auction), 180);
+    clock.advance(Duration::from_secs(160));
+    assert!(bid(&mut auction, "alice", 10));
+    assert_eq!(secs(&auction), 20);
+    assert!(auction.extensions.is_empty());
+
+    clock.advance(Duration::from_secs(10));
+    assert!(bid(&mut auction, "bob", 20));
+    assert_eq!(secs(&auction), 25);
+    assert_eq!(auction.extensions.len(), 1);
+    assert_eq!(auction.extensions[0].before, 10);
+    assert_eq!(auction.extensions[0].after, 25);
+
+    //  Reset: The timer is set back to the Helmet value.
+    let (clock, mut auction) = auction_manual(|opt| {
+        opt.duration = Duration::from_secs(60);
+        opt.helmet = Duration::from_secs(15);
+        opt.helmet_policy = HelmetPolicy::Reset;
+    });
+
+    clock.advance(Duration::from_secs(55));
+    assert!(bid(&mut auction, "alice", 10));
+    assert_eq!(secs(&auction), 15);
+
+    //  A bid right after a reset adds less than a second, and is not counted.
+    assert!(bid(&mut auction, "bob", 20));
+    assert_eq!(secs(&auction), 15);
+    assert_eq!(auction.extensions.len(), 1);
+
+    //  Limits on the number of extensions, and on the total time added.
+    let (clock, mut auction) = auction_manual(|opt| {
+        opt.duration = Duration::from_secs(60);
+        opt.helmet = Duration::from_secs(15);
+        opt.helmet_extension = Some(Duration::from_secs(10));
+        opt.helmet_max_total = Some(Duration::from_secs(15));
+        opt.helmet_max_count = Some(2);
+    });
+
+    clock.advance(Duration::from_secs(55));
+    assert!(bid(&mut auction, "alice", 10));
+    assert_eq!(secs(&auction), 15);
+    assert!(bid(&mut auction, "bob", 20));
+    assert_eq!(secs(&auction), 15);
+
+    clock.advance(Duration::from_secs(10));
+    assert!(bid(&mut auction, "alice", 30));
+    assert_eq!(secs(&auction), 10);
+    assert_eq!(auction.extensions.len(), 2);
+
+    clock.advance(Duration::from_secs(5));
+    assert!(bid(&mut auction, "bob", 40));
+    assert_eq!(secs(&auction), 5);
+    assert_eq!(auction.extensions.len(), 2);
+}
+
+
+#[test]
+fn test_disconnect() {
+    use std::time::Duration;
+    use bot::auction::PauseReason;
+    use currency::Money;
+
+    let (clock, mut auction) = auction_manual(|opt| {
+        opt.duration = Duration::from_secs(120);
+    });
+
+    clock.advance(Duration::from_secs(20));
+    assert!(auction.pause(PauseReason::Disconnect, None));
+    assert!(!auction.pause(PauseReason::Disconnect, None));
+    assert_eq!(auction.paused(), Some(PauseReason::Disconnect));
+
+    //  Time does not pass for the Auction while the bot is away, and nobody
+    //      can bid.
+    clock.advance(Duration::from_secs(300));
+    assert_eq!(auction.remaining(), Some(Duration::from_secs(100)));
+    assert!(auction.bid("alice", Money::whole(10), "").is_rejection());
+
+    assert_eq!(auction.resume(), Some(Duration::from_secs(300)));
+    assert_eq!(auction.resume(), None);
+    assert_eq!(auction.remaining(), Some(Duration::from_secs(100)));
+    assert_eq!(auction.pauses.len(), 1);
+    assert_eq!(auction.pauses[0].duration, 300);
+
+    clock.advance(Duration::from_secs(40));
+    assert_eq!(auction.remaining(), Some(Duration::from_secs(60)));
+    assert!(!auction.bid("alice", Money::whole(10), "").is_rejection());
+}
+
+
+#[test]
+fn test_announcements() {
+    use std::time::Duration;
+    use bot::{auction::Auction, auction_check, AuctionStatus};
+    use currency::Money;
+
+    let (clock, auction) = auction_manual(|opt| {
+        opt.duration = Duration::from_secs(120);
+    });
+    let mut lock: Option<Auction> = Some(auction);
+
+    let check = |lock: &mut Option<Auction>, advance: u64| {
+        clock.advance(Duration::from_secs(advance));
+
+        match auction_check(lock) {
+            AuctionStatus::Active(text) => text,
+            AuctionStatus::Ended(text, auction) => {
+                assert!(auction.remaining().is_none());
+                Some(text)
+            }
+            AuctionStatus::Inactive => None,
+        }
+    };
+    let text = |text: &str| Some(String::from(text));
+
+    //  The Auction thread checks a moment after each second passes, so the
+    //      Clock is kept half a second off the whole seconds.
+    clock.advance(Duration::from_millis(500));
+    assert_eq!(check(&mut lock, 0), text("Auction: 120 seconds remain."));
+    assert_eq!(check(&mut lock, 1), None);
+    assert_eq!(check(&mut lock, 58), None);
+    assert_eq!(check(&mut lock, 1), text("Auction: 60 seconds remain."));
+
+    lock.as_mut().unwrap().bid("alice", Money::whole(10), "");
+    assert_eq!(
+        check(&mut lock, 30),
+        text("Auction: 30 seconds remain. The current bid is $10."),
+    );
+    assert_eq!(check(&mut lock, 25), text("Auction: 5..."));
+    assert_eq!(check(&mut lock, 4), text("Auction: 1..."));
+    assert_eq!(
+        check(&mut lock, 1),
+        text("The Auction has been won by @alice, with a bid of $10."),
+    );
+    assert!(lock.is_none());
+    assert_eq!(check(&mut lock, 1), None);
+}
+
+
+#[test]
+fn test_announce_schedule() {
+    use std::time::Duration;
+    use bot::{auction::Auction, auction_check, AuctionStatus};
+
+    let (clock, auction) = auction_manual(|opt| {
+        opt.duration = Duration::from_secs(120);
+        opt.announce_at = Some(vec![Duration::from_secs(90), Duration::from_secs(45)]);
+        opt.countdown = 2;
+    });
+    let mut lock: Option<Auction> = Some(auction);
+
+    clock.advance(Duration::from_millis(500));
+
+    //  Collect every announcement made, second by second, until the end.
+    let mut said: Vec<(u64, String)> = Vec::new();
+
+    for left in (0..=119).rev() {
+        clock.advance(Duration::from_secs(1));
+
+        match auction_check(&mut lock) {
+            AuctionStatus::Active(Some(text)) => said.push((left, text)),
+            AuctionStatus::Ended(..) => break,
+            _ => {}
+        }
+    }
+
+    assert_eq!(said, [
+        (90, String::from("Auction: 90 seconds remain.")),
+        (45, String::from("Auction: 45 seconds remain.")),
+        (2, String::from("Auction: 2...")),
+        (1, String::from("Auction: 1...")),
+    ]);
+
+    //  With announcements off, nothing is said until the Auction ends.
+    let (clock, auction) = auction_manual(|opt| {
+        opt.duration = Duration::from_secs(60);
+        opt.announce_at = Some(Vec::new());
+        opt.countdown = 0;
+    });
+    let mut lock: Option<Auction> = Some(auction);
+
+    clock.advance(Duration::from_millis(500));
+
+    for _ in 0..60 {
+        assert!(matches!(auction_check(&mut lock), AuctionStatus::Active(None)));
+        clock.advance(Duration::from_secs(1));
+    }
+
+    assert!(matches!(auction_check(&mut lock), AuctionStatus::Ended(..)));
+}
+
+
+#[test]
+fn test_schedule() {
+    use std::{sync::Arc, time::Duration};
+    use bot::{
+        clock::{Clock, ManualClock},
+        Cooldowns,
+        schedule::{AuctionSetup, parse_when, Scheduled, WhenError},
+    };
+
+    let secs = Duration::from_secs;
+
+    //  A delay may be written across several words, and the options follow it.
+    assert_eq!(
+        parse_when(&["in", "1h", "30m", "--prize", "Hat"]),
+        Ok((secs(5400), &["--prize", "Hat"][..])),
+    );
+    assert_eq!(parse_when(&["in", "90"]), Ok((secs(90), &[][..])));
+    assert_eq!(parse_when(&["in", "soon"]), Err(WhenError::Invalid));
+    assert_eq!(parse_when(&["in"]), Err(WhenError::Invalid));
+    assert_eq!(parse_when(&["25:00"]), Err(WhenError::Invalid));
+
+    //  A time of day is always within the next day.
+    #[cfg(feature = "chrono")]
+    if let Ok((delay, rest)) = parse_when(&["20:30", "-t", "300"]) {
+        assert!(delay <= secs(86_400));
+        assert_eq!(rest, ["-t", "300"]);
+    }
+
+    let config: Config = toml::from_str(include_str!("../src/cfg_default.toml")).unwrap();
+    let clock = Arc::new(ManualClock::new());
+    let setup = AuctionSetup {
+        options: config.auction_options(""),
+        prize: Some(String::from("A Hat")),
+        verb: String::from("pay"),
+        channels: Vec::new(),
+        ledger: None,
+        clock: clock.clone(),
+    };
+
+    //  Lead times that have already passed are not announced.
+    let leads = [secs(1800), secs(600), secs(60)];
+    let mut scheduled = Scheduled::new(setup, String::from("mod"), clock.now() + secs(900), &leads);
+    assert_eq!(scheduled.until(), secs(900));
+    assert_eq!(scheduled.announce(&leads), None);
+
+    clock.advance(Duration::from_millis(300_500));
+    assert_eq!(scheduled.announce(&leads), Some(secs(600)));
+    assert_eq!(scheduled.announce(&leads), None);
+    assert!(!scheduled.is_due());
+
+    //  An attempt to bid just before the Auction starts does not hold the
+    //      bidder back once it does, as with an Auction started by hand.
+    clock.advance(secs(599));
+    let mut cooldowns = Cooldowns::default();
+    assert!(!cooldowns.throttle("alice", secs(3), clock.now(), clock.now()));
+
+    clock.advance(Duration::from_millis(500));
+    assert!(scheduled.is_due());
+    assert_eq!(scheduled.until(), Duration::ZERO);
+
+    let (auction, verb) = scheduled.setup.begin();
+    assert_eq!(verb, "pay");
+    assert_eq!(auction.remaining(), Some(secs(180)));
+    assert!(!cooldowns.throttle("alice", secs(3), auction.time_begin, clock.now()));
+    assert!(cooldowns.throttle("alice", secs(3), auction.time_begin, clock.now()));
+}
+
+
+#[test]
+fn test_config_defaults() {
+    let default: &str = include_str!("../src/cfg_default.toml");
+
+    //  A Config written before `enforce_max_raise_on_first` existed keeps the
+    //      limit on the first bid.
+    let old: String = default.lines()
+        .filter(|line| !line.starts_with("enforce_max_raise_on_first"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let config: Config = toml::from_str(&old).unwrap();
+
+    assert!(config.enforce_max_raise_on_first(""));
+    assert!(config.auction_options("").opening_limit().is_some());
+}
+
+
+#[test]
+fn test_presets() {
+    use std::time::Duration;
+    use bot::auction::{AuctionOptions, Increment};
+    use currency::Money;
+
+    let default: &str = include_str!("../src/cfg_default.toml");
+    let text: String = format!(
+        "{}\n[channel.other.preset.Big]\nduration = 60\nverb = \"donate\"\n",
+        default,
+    );
+    let config: Config = toml::from_str(&text).unwrap();
+
+    //  A preset replaces only the options it sets.
+    let mut opt: AuctionOptions = config.auction_options("");
+    config.preset("", "BIG").unwrap().apply(&mut opt);
+    assert_eq!(opt.duration, Duration::from_secs(600));
+    assert_eq!(opt.helmet, Duration::from_secs(30));
+    assert_eq!(opt.min_bid, Money::whole(50));
+    assert_eq!(opt.min_raise, Increment::Absolute(Money::whole(1)));
+
+    //  It also takes precedence over the options of a channel.
+    let mut opt: AuctionOptions = config.auction_options("gamesdonequick");
+    assert_eq!(opt.duration, Duration::from_secs(10));
+    config.preset("gamesdonequick", "big").unwrap().apply(&mut opt);
+    assert_eq!(opt.duration, Duration::from_secs(600));
+    assert_eq!(opt.helmet, Duration::from_secs(30));
+
+    //  A preset of a channel takes the place of a global one of the same name.
+    let local = config.preset("other", "big").unwrap();
+    let mut opt: AuctionOptions = config.auction_options("other");
+    local.apply(&mut opt);
+    assert_eq!(opt.duration, Duration::from_secs(60));
+    assert_eq!(opt.min_bid, Money::whole(5));
+    assert_eq!(local.verb(), Some("donate"));
+    assert_eq!(config.presets("other").len(), 1);
+
+    //  An unknown name finds nothing.
+    assert!(config.preset("", "huge").is_none());
+    assert!(config.preset("gamesdonequick", "").is_none());
+}
+
+
+#[test]
+fn test_increment() {
+    use bot::auction::{BidResult, Increment};
+    use currency::Money;
+
+    //  A raise must clear the minimum increment.
+    let (_, mut auction) = auction_manual(|opt| {
+        opt.min_raise = Increment::Absolute(Money::whole(5));
+    });
+
+    assert!(matches!(auction.bid("alice", Money::whole(10), ""), BidResult::Ok { .. }));
+    assert!(matches!(
+        auction.bid("bob", Money::whole(12), ""),
+        BidResult::BelowIncrement(next) if next == Money::whole(15),
+    ));
+    assert!(matches!(auction.bid("bob", Money::whole(15), ""), BidResult::Ok { .. }));
+
+    //  A percentage grows with the bid, and is rounded up to the smallest
+    //      unit of the currency.
+    let (_, mut auction) = auction_manual(|opt| {
+        opt.min_raise = "10%".parse().unwrap();
+    });
+
+    assert!(matches!(auction.bid("alice", Money::whole(20), ""), BidResult::Ok { .. }));
+    assert!(matches!(
+        auction.bid("bob", Money::from_cents(2199), ""),
+        BidResult::BelowIncrement(next) if next == Money::whole(22),
+    ));
+    assert!(matches!(auction.bid("bob", Money::whole(22), ""), BidResult::Ok { .. }));
+    assert!(matches!(
+        auction.bid("alice", Money::from_cents(2419), ""),
+        BidResult::BelowIncrement(next) if next == Money::from_cents(2420),
+    ));
+
+    //  A minimum increment that no raise could satisfy is refused.
+    let default: &str = include_str!("../src/cfg_default.toml");
+    let config: Config = toml::from_str(default).unwrap();
+    assert!(config.validate().is_ok());
+
+    for min_raise in ["600", "\"20000%\""] {
+        let text: String = default.replace(
+            "\nmin_raise = 1\n",
+            &format!("\nmin_raise = {}\n", min_raise),
+        );
+        let config: Config = toml::from_str(&text).unwrap();
+        assert!(!config.auction_options("").raises_possible());
+        assert!(config.validate().is_err());
+    }
+}
+
+
+#[test]
+fn test_adjust_time() {
+    use std::time::Duration;
+    use bot::{auction::{Auction, AuctionMode, PauseReason}, auction_check, AuctionStatus};
+
+    let secs = Duration::from_secs;
+    let (clock, mut auction) = auction_manual(|_| {});
+    clock.advance(secs(30));
+
+    //  Every change is made from the time remaining, and recorded.
+    assert_eq!(auction.extend("mod", secs(60)), Some(secs(210)));
+    assert_eq!(auction.remaining(), Some(secs(210)));
+    assert_eq!(auction.shorten("mod", secs(200)), Some(secs(10)));
+    assert_eq!(auction.set_remaining("mod", secs(45)), Some(secs(45)));
+    clock.advance(secs(40));
+    assert_eq!(auction.remaining(), Some(secs(5)));
+
+    let changes: Vec<(u64, u64)> = auction.adjustments.iter()
+        .map(|adj| (adj.before, adj.after))
+        .collect();
+    assert_eq!(changes, [(150, 210), (210, 10), (10, 45)]);
+
+    //  Time added during a pause is kept for when the Auction resumes.
+    assert!(auction.pause(PauseReason::Operator, None));
+    clock.advance(secs(100));
+    assert_eq!(auction.extend("mod", secs(20)), Some(secs(25)));
+    auction.resume();
+    assert_eq!(auction.remaining(), Some(secs(25)));
+
+    //  Taking away more than remains ends the Auction at once, after which
+    //      its time can no longer be changed.
+    assert_eq!(auction.shorten("mod", secs(600)), Some(Duration::ZERO));
+
+    let mut lock: Option<Auction> = Some(auction);
+    let mut auction: Auction = match auction_check(&mut lock) {
+        AuctionStatus::Ended(_, auction) => *auction,
+        _ => panic!("Auction did not end"),
+    };
+    assert_eq!(auction.extend("mod", secs(60)), None);
+    assert_eq!(auction.shorten("mod", secs(60)), None);
+
+    //  The length of a Dutch Auction is set by its prices.
+    let (_, mut auction) = auction_manual(|opt| {
+        opt.mode = AuctionMode::Dutch;
+        opt.price_start = Some(currency::Money::whole(50));
+    });
+    assert_eq!(auction.extend("mod", secs(60)), None);
+}
+
+
+#[test]
+fn test_buy_now() {
+    use bot::auction::{AuctionMode, BidResult, Closure};
+    use currency::Money;
+
+    let (_, mut auction) = auction_manual(|opt| {
+        opt.buy_now = Some(Money::whole(100));
+    });
+
+    assert!(matches!(auction.bid("alice", Money::whole(10), ""), BidResult::Ok { .. }));
+
+    //  The leader may not jump to the buy-it-now price over their own bid.
+    assert!(matches!(
+        auction.bid("alice", Money::whole(150), ""),
+        BidResult::RepeatBidder(bid) if bid == Money::whole(10),
+    ));
+    assert_eq!(auction.closure, None);
+
+    //  A bid above the price only pays the price, and ends the Auction.
+    assert!(matches!(
+        auction.bid("bob", Money::whole(150), ""),
+        BidResult::BuyNow(price) if price == Money::whole(100),
+    ));
+    assert_eq!(auction.closure, Some(Closure::BuyNow));
+    assert!(matches!(auction.bid("carol", Money::whole(200), ""), BidResult::Closed));
+
+    let winner = auction.winner().unwrap();
+    assert_eq!(winner.name, "bob");
+    assert_eq!(winner.amount, Money::whole(100));
+
+    //  A sealed bid may be revised up to the price.
+    let (_, mut auction) = auction_manual(|opt| {
+        opt.mode = AuctionMode::Sealed;
+        opt.buy_now = Some(Money::whole(100));
+    });
+
+    assert!(matches!(
+        auction.bid("alice", Money::whole(20), ""),
+        BidResult::Sealed { revised: false },
+    ));
+    assert!(matches!(auction.bid("alice", Money::whole(100), ""), BidResult::BuyNow(..)));
+
+    //  A price below the minimum bid does not let bids under the minimum in.
+    let (_, mut auction) = auction_manual(|opt| {
+        opt.buy_now = Some(Money::whole(3));
+    });
+
+    assert!(matches!(
+        auction.bid("alice", Money::whole(4), ""),
+        BidResult::BelowMinimum(min) if min == Money::whole(5),
+    ));
+    assert_eq!(auction.closure, None);
+}
+
+
+#[test]
+fn test_dutch() {
+    use std::time::Duration;
+    use bot::auction::{AuctionMode, BidResult, Closure};
+    use currency::Money;
+
+    let (clock, mut auction) = auction_manual(|opt| {
+        opt.mode = AuctionMode::Dutch;
+        opt.price_start = Some(Money::whole(100));
+        opt.price_floor = Some(Money::whole(50));
+        opt.price_step = Money::whole(10);
+        opt.price_interval = Duration::from_secs(10);
+    });
+
+    //  One interval for each price from the start to the floor.
+    assert_eq!(auction.remaining(), Some(Duration::from_secs(60)));
+    assert_eq!(auction.price(), Some(Money::whole(100)));
+
+    clock.advance(Duration::from_secs(25));
+    assert_eq!(auction.price(), Some(Money::whole(80)));
+    assert!(matches!(
+        auction.bid("alice", Money::whole(70), ""),
+        BidResult::BelowPrice(price) if price == Money::whole(80),
+    ));
+
+    //  The price never falls below the floor.
+    clock.advance(Duration::from_secs(30));
+    assert_eq!(auction.price(), Some(Money::whole(50)));
+
+    assert!(matches!(
+        auction.buy("bob", ""),
+        BidResult::Bought(price) if price == Money::whole(50),
+    ));
+    assert_eq!(auction.closure, Some(Closure::Purchase));
+    assert_eq!(auction.winner().unwrap().price_paid, Money::whole(50));
+
+    //  Lengths that cannot be reached or represented are refused.
+    let (_, auction) = auction_manual(|opt| {
+        opt.mode = AuctionMode::Dutch;
+        opt.price_start = Some(Money::whole(100));
+        opt.price_step = Money::ZERO;
+    });
+    assert_eq!(auction.options.dutch_length(), None);
+
+    let (_, auction) = auction_manual(|opt| {
+        opt.mode = AuctionMode::Dutch;
+        opt.price_start = Some(Money::whole(100_000_000));
+        opt.price_step = Money::from_cents(1);
+    });
+    assert_eq!(auction.options.dutch_length(), None);
+}
+
+
+#[test]
+fn test_reserve() {
+    use std::time::Duration;
+    use bot::{auction::Auction, auction_check, AuctionStatus};
+    use currency::Money;
+
+    let end = |bids: &[(&str, u64)]| {
+        let (clock, mut auction) = auction_manual(|opt| {
+            opt.reserve = Some(Money::whole(50));
+        });
+
+        for &(name, amount) in bids {
+            assert!(!auction.bid(name, Money::whole(amount), "").is_rejection());
+        }
+
+        clock.advance(Duration::from_secs(200));
+        let mut lock: Option<Auction> = Some(auction);
+
+        match auction_check(&mut lock) {
+            AuctionStatus::Ended(text, auction) => (text, auction.winner()),
+            _ => panic!("Auction did not end"),
+        }
+    };
+
+    //  Without any bids, the reserve is not mentioned.
+    let (text, winner) = end(&[]);
+    assert_eq!(text, "The Auction has ended with no bids.");
+    assert!(winner.is_none());
+
+    let (text, winner) = end(&[("alice", 10), ("bob", 40)]);
+    assert_eq!(text, "The Auction has ended, but the reserve price was not met. There is no winner.");
+    assert!(winner.is_none());
+
+    let (text, winner) = end(&[("alice", 10), ("bob", 50)]);
+    assert_eq!(text, "The Auction has been won by @bob, with a bid of $50.");
+    assert_eq!(winner.unwrap().price_paid, Money::whole(50));
+}
+
+
+#[test]
+fn test_eligibility() {
+    use bot::auction::Eligibility;
+
+    let rules = |text: &str| text.parse::<Eligibility>().unwrap();
+
+    //  Badge names are understood in several forms, and written back in one.
+    let subs_vip: Eligibility = rules("Subs, vip");
+    assert_eq!(subs_vip.allow, ["subscriber", "vip"]);
+    assert!(subs_vip.deny.is_empty());
+    assert_eq!(subs_vip.to_string(), "subscriber,vip");
+    assert_eq!(subs_vip.explain(), "Only subscribers or VIPs may bid.");
+
+    assert!(rules("all").is_open());
+    assert!(rules("").is_open());
+    assert!("subs,!".parse::<Eligibility>().is_err());
+
+    //  A bidder needs at least one of the listed badges. Founders count as
+    //      subscribers.
+    assert!(subs_vip.admits(&["subscriber"]));
+    assert!(subs_vip.admits(&["founder"]));
+    assert!(subs_vip.admits(&["VIP", "turbo"]));
+    assert!(!subs_vip.admits(&["turbo"]));
+    assert!(!subs_vip.admits(&[]));
+
+    //  Followers have no badge from Twitch, and can only be singled out by a
+    //      custom one.
+    let followers: Eligibility = rules("follower");
+    assert!(followers.admits(&["follower"]));
+    assert!(!followers.admits(&["subscriber"]));
+
+    //  An excluded badge refuses a bidder even if they have an allowed one.
+    let no_mods: Eligibility = rules("subs,!mods");
+    assert_eq!(no_mods.deny, ["moderator"]);
+    assert!(no_mods.admits(&["subscriber"]));
+    assert!(!no_mods.admits(&["subscriber", "moderator"]));
+    assert!(rules("!turbo").admits(&[]));
+    assert!(!rules("!turbo").admits(&["turbo"]));
+}
+
+
+#[test]
+fn test_cooldown() {
+    use std::time::Duration;
+    use bot::{clock::Clock, reply_to_rejection, Cooldowns};
+
+    let (clock, mut auction) = auction_manual(|_| {});
+    let mut cooldowns = Cooldowns::default();
+    let cooldown = Duration::from_secs(3);
+    let begin = auction.time_begin;
+    let mut throttle = |name: &str| cooldowns.throttle(name, cooldown, begin, clock.now());
+
+    //  A second attempt inside the cooldown is suppressed, for that user only.
+    assert!(!throttle("alice"));
+    clock.advance(Duration::from_secs(1));
+    assert!(throttle("Alice"));
+    assert!(!throttle("bob"));
+
+    //  A suppressed attempt does not restart the cooldown.
+    clock.advance(Duration::from_secs(1));
+    assert!(throttle("alice"));
+    clock.advance(Duration::from_secs(1));
+    assert!(!throttle("alice"));
+    assert!(throttle("alice"));
+
+    assert!(!cooldowns.throttle("alice", Duration::ZERO, begin, clock.now()));
+
+    //  Refusals past the cap are counted, but no longer answered.
+    let replies: Vec<bool> = (0..4).map(|_| reply_to_rejection(&mut auction, Some(2))).collect();
+    assert_eq!(replies, [true, true, false, false]);
+    assert_eq!((auction.rejected, auction.muted), (4, 2));
+    assert!(reply_to_rejection(&mut auction, None));
+}
+
+
+#[test]
+fn test_bits() {
+    use std::time::Duration;
+    use bot::{auction::{Auction, BitsMode}, auction_check, AuctionStatus};
+    use currency::Money;
+
+    assert_eq!("Bids".parse(), Ok(BitsMode::Bid));
+    assert_eq!("payment".parse(), Ok(BitsMode::Deposit));
+    assert_eq!("none".parse(), Ok(BitsMode::Off));
+    assert_eq!("cheers".parse::<BitsMode>(), Err(()));
+
+    //  In the Bid mode, the value of the cheer is the bid. The Bits of a
+    //      refused bid are counted separately.
+    let (clock, mut auction) = auction_manual(|opt| {
+        opt.bits = BitsMode::Bid;
+        opt.bit_value = Money::from_cents(2);
+    });
+
+    assert_eq!(auction.bits_value(500), Money::whole(10));
+    assert!(!auction.bid_cheered("alice", auction.bits_value(500), "", 500).is_rejection());
+    assert!(auction.bid_cheered("bob", auction.bits_value(250), "", 250).is_rejection());
+    assert!(!auction.bid_cheered("bob", auction.bits_value(600), "", 600).is_rejection());
+    assert_eq!(auction.bits_refused, 250);
+
+    clock.advance(Duration::from_secs(200));
+    let winner = auction.winner().unwrap();
+    assert_eq!((winner.name.as_str(), winner.amount), ("bob", Money::whole(12)));
+    assert_eq!(winner.bits, Some(600));
+
+    //  In the Deposit mode, the cheers of the winner go toward the price.
+    let (clock, mut auction) = auction_manual(|opt| {
+        opt.bits = BitsMode::Deposit;
+    });
+
+    assert!(!auction.bid_cheered("alice", Money::whole(20), "", 300).is_rejection());
+    assert!(!auction.bid("bob", Money::whole(21), "").is_rejection());
+    assert!(!auction.bid_cheered("alice", Money::whole(25), "", 200).is_rejection());
+
+    let mut lock: Option<Auction> = Some(auction);
+    clock.advance(Duration::from_secs(200));
+
+    match auction_check(&mut lock) {
+        AuctionStatus::Ended(text, _) => assert_eq!(
+            text,
+            "The Auction has been won by @alice, with a bid of $25. The 500 Bits \
+            cheered with the bids count for $5, leaving $20 to be paid.",
+        ),
+        _ => panic!("Auction did not end"),
+    }
+}
+
+
+#[test]
+fn test_wallet() {
+    use std::{sync::Arc, time::Duration};
+    use parking_lot::Mutex;
+    use bot::auction::BidResult;
+    use currency::Money;
+    use saving::Ledger;
+
+    //  A Ledger without an Auction ID is never written to a file.
+    let ledger = Arc::new(Mutex::new(Ledger::default()));
+    ledger.lock().grant("alice", Money::whole(100));
+    ledger.lock().grant("@Bob", Money::whole(80));
+
+    let (clock, mut auction) = auction_manual(|_| {});
+    auction.ledger = Some(ledger.clone());
+
+    //  Balances are kept by login, even for a bidder whose name differs.
+    auction.note_login("アリス", "alice");
+    assert_eq!(auction.balance("アリス"), Some(Money::whole(100)));
+    assert_eq!(auction.balance("BOB"), Some(Money::whole(80)));
+
+    assert!(matches!(auction.bid("アリス", Money::whole(50), ""), BidResult::Ok { .. }));
+    assert!(matches!(
+        auction.bid("bob", Money::whole(90), ""),
+        BidResult::AboveBalance(balance) if balance == Money::whole(80),
+    ));
+    assert!(matches!(auction.bid("bob", Money::whole(60), ""), BidResult::Ok { .. }));
+
+    clock.advance(Duration::from_secs(200));
+    auction.charge_winner();
+    assert_eq!(ledger.lock().balance("bob"), Money::whole(20));
+    assert_eq!(ledger.lock().balance("alice"), Money::whole(100));
+
+    //  A disqualified winner is refunded, and the next bidder charged.
+    let (old, new) = auction.reassign().unwrap();
+    assert_eq!(old, "bob");
+    assert_eq!(new.unwrap().name, "アリス");
+    assert_eq!(ledger.lock().balance("bob"), Money::whole(80));
+    assert_eq!(ledger.lock().balance("alice"), Money::whole(50));
+
+    ledger.lock().revoke("alice", Money::whole(70));
+    assert_eq!(ledger.lock().balance("alice"), Money::ZERO);
+
+    //  A winner who could not cover the price is refunded only what was taken.
+    ledger.lock().grant("carol", Money::whole(30));
+    ledger.lock().grant("dave", Money::whole(60));
+
+    let (clock, mut auction) = auction_manual(|_| {});
+    auction.ledger = Some(ledger.clone());
+
+    assert!(matches!(auction.bid("carol", Money::whole(20), ""), BidResult::Ok { .. }));
+    assert!(matches!(auction.bid("dave", Money::whole(60), ""), BidResult::Ok { .. }));
+    ledger.lock().revoke("dave", Money::whole(50));
+
+    clock.advance(Duration::from_secs(200));
+    auction.charge_winner();
+    assert_eq!(ledger.lock().balance("dave"), Money::ZERO);
+
+    let (old, new) = auction.reassign().unwrap();
+    assert_eq!(old, "dave");
+    assert_eq!(new.unwrap().name, "carol");
+    assert_eq!(ledger.lock().balance("dave"), Money::whole(10));
+    assert_eq!(ledger.lock().balance("carol"), Money::whole(10));
+}
+
+
+#[test]
+fn test_sealed() {
+    use std::time::Duration;
+    use bot::{auction::{Auction, AuctionMode, BidResult}, auction_check, AuctionStatus};
+    use currency::Money;
+
+    let (clock, mut auction) = auction_manual(|opt| {
+        opt.mode = AuctionMode::Sealed;
+    });
+    let mut bid = |name: &str, amount: u64| auction.bid(name, Money::whole(amount), "");
+
+    assert!(matches!(bid("alice", 30), BidResult::Sealed { revised: false }));
+    assert!(matches!(bid("bob", 25), BidResult::Sealed { revised: false }));
+    assert!(matches!(bid("carol", 600), BidResult::AboveOpening(..)));
+    assert!(matches!(bid("carol", 2), BidResult::BelowMinimum(..)));
+
+    //  A revised bid replaces the earlier one, even if it is lower. Among
+    //      equal bids, the earliest wins.
+    assert!(matches!(bid("alice", 20), BidResult::Sealed { revised: true }));
+    assert!(matches!(bid("carol", 25), BidResult::Sealed { revised: false }));
+
+    let mut lock: Option<Auction> = Some(auction);
+
+    //  Announcements do not reveal any amount.
+    clock.advance(Duration::from_millis(120_500));
+    match auction_check(&mut lock) {
+        AuctionStatus::Active(Some(text)) => {
+            assert_eq!(text, "Auction: 60 seconds remain to submit a sealed bid.");
+        }
+        _ => panic!("Auction was not announced"),
+    }
+
+    clock.advance(Duration::from_secs(60));
+    match auction_check(&mut lock) {
+        AuctionStatus::Ended(text, auction) => {
+            assert_eq!(text, "The Sealed Auction has been won by @bob, with a bid of $25.");
+            assert_eq!(auction.standings().len(), 3);
+        }
+        _ => panic!("Auction did not end"),
+    }
+}
+
+
+#[test]
+fn test_second_price() {
+    use bot::auction::{AuctionMode, Settlement};
+    use currency::Money;
+
+    let price = |mode: AuctionMode, reserve: Option<u64>, bids: &[(&str, u64)]| {
+        let (_, mut auction) = auction_manual(|opt| {
+            opt.mode = mode;
+            opt.settlement = Settlement::Second;
+            opt.reserve = reserve.map(Money::whole);
+        });
+
+        for &(name, amount) in bids {
+            assert!(!auction.bid(name, Money::whole(amount), "").is_rejection());
+        }
+
+        auction.winner().map(|winner| (winner.name, winner.amount, winner.price_paid))
+    };
+    let won = |name: &str, amount: u64, paid: u64| {
+        Some((String::from(name), Money::whole(amount), Money::whole(paid)))
+    };
+
+    //  The winner pays the second-highest bid.
+    assert_eq!(price(AuctionMode::Open, None, &[("alice", 10), ("bob", 40)]), won("bob", 40, 10));
+    assert_eq!(
+        price(AuctionMode::Sealed, None, &[("alice", 30), ("bob", 40), ("carol", 35)]),
+        won("bob", 40, 35),
+    );
+
+    //  Without a second bid, the minimum is paid, and never less than the
+    //      reserve.
+    assert_eq!(price(AuctionMode::Open, None, &[("alice", 40)]), won("alice", 40, 5));
+    assert_eq!(
+        price(AuctionMode::Open, Some(20), &[("alice", 10), ("bob", 40)]),
+        won("bob", 40, 20),
+    );
+}
+
+
+#[test]
+fn test_proxy() {
+    use bot::auction::{Auction, AuctionMode, BidResult, Increment, ProxyResult};
+    use currency::Money;
+
+    let lead = |auction: &Auction| auction.last_bid()
+        .map(|bid| (bid.bidder.clone(), bid.amount));
+    let at = |name: &str, amount: u64| Some((String::from(name), Money::whole(amount)));
+
+    let (_, mut auction) = auction_manual(|_| {});
+
+    //  A maximum bid opens at the minimum.
+    assert!(matches!(
+        auction.set_proxy("alice", Money::whole(50), ""),
+        ProxyResult::Set { proxied } if proxied == [(String::from("alice"), Money::whole(5))],
+    ));
+
+    //  Outbidding a maximum only raises it by as little as possible.
+    assert!(matches!(
+        auction.bid("bob", Money::whole(20), ""),
+        BidResult::Ok { proxied, .. } if proxied == [(String::from("alice"), Money::whole(21))],
+    ));
+    assert_eq!(lead(&auction), at("alice", 21));
+
+    //  A lower maximum pushes the leader just past it.
+    assert!(matches!(auction.set_proxy("bob", Money::whole(40), ""), ProxyResult::Set { .. }));
+    assert_eq!(lead(&auction), at("alice", 41));
+
+    //  An equal maximum registered later does not take the lead.
+    assert!(matches!(auction.set_proxy("carol", Money::whole(50), ""), ProxyResult::Set { .. }));
+    assert_eq!(lead(&auction), at("alice", 50));
+
+    assert!(matches!(
+        auction.set_proxy("dave", Money::whole(45), ""),
+        ProxyResult::TooLow(min) if min == Money::whole(51),
+    ));
+    assert!(matches!(auction.bid("dave", Money::whole(60), ""), BidResult::Ok { .. }));
+    assert_eq!(lead(&auction), at("dave", 60));
+
+    //  A lower maximum does not step in where a large increment would leave
+    //      the leader unable to answer it.
+    let (_, mut auction) = auction_manual(|opt| {
+        opt.min_raise = Increment::Absolute(Money::whole(5));
+    });
+
+    auction.set_proxy("alice", Money::whole(42), "");
+    auction.bid("bob", Money::whole(30), "");
+    assert_eq!(lead(&auction), at("alice", 35));
+    auction.set_proxy("bob", Money::whole(40), "");
+    assert_eq!(lead(&auction), at("alice", 35));
+
+    //  With raises capped, the two maximums take turns stepping up, and the
+    //      leader never raises against themselves.
+    let (_, mut auction) = auction_manual(|opt| {
+        opt.max_raise = Money::whole(10);
+    });
+
+    auction.set_proxy("alice", Money::whole(100), "");
+    match auction.set_proxy("bob", Money::whole(50), "") {
+        ProxyResult::Set { proxied } => assert_eq!(proxied, [
+            (String::from("bob"), Money::whole(15)),
+            (String::from("alice"), Money::whole(25)),
+            (String::from("bob"), Money::whole(35)),
+            (String::from("alice"), Money::whole(45)),
+            (String::from("bob"), Money::whole(50)),
+            (String::from("alice"), Money::whole(51)),
+        ]),
+        _ => panic!("maximum bid refused"),
+    }
+    assert_eq!(lead(&auction), at("alice", 51));
+
+    //  Only open Auctions have maximum bids.
+    let (_, mut auction) = auction_manual(|opt| {
+        opt.mode = AuctionMode::Sealed;
+    });
+    assert!(matches!(
+        auction.set_proxy("alice", Money::whole(50), ""),
+        ProxyResult::Unavailable,
+    ));
+}
+
+
+#[test]
+fn test_reassign() {
+    use std::time::Duration;
+    use bot::auction::Settlement;
+    use currency::Money;
+
+    let (clock, mut auction) = auction_manual(|opt| {
+        opt.settlement = Settlement::Second;
+    });
+
+    auction.bid("alice", Money::whole(10), "");
+    auction.bid("bob", Money::whole(20), "");
+    auction.bid("carol", Money::whole(30), "");
+
+    //  Only a finished Auction can be reassigned.
+    assert!(auction.reassign().is_none());
+    clock.advance(Duration::from_secs(200));
+    assert_eq!(auction.winner().unwrap().price_paid, Money::whole(20));
+
+    //  The runner-up pays their own bid.
+    let (old, new) = auction.reassign().unwrap();
+    let new = new.unwrap();
+    assert_eq!(old, "carol");
+    assert_eq!((new.name.as_str(), new.price_paid), ("bob", Money::whole(20)));
+
+    let (old, new) = auction.reassign().unwrap();
+    assert_eq!(old, "bob");
+    assert_eq!(new.unwrap().name, "alice");
+
+    let (old, new) = auction.reassign().unwrap();
+    assert_eq!(old, "alice");
+    assert!(new.is_none());
+    assert!(auction.reassign().is_none());
+    assert_eq!(auction.disqualified, ["carol", "bob", "alice"]);
+}
+
+
+#[test]
+fn test_shared() {
+    use std::time::Duration;
+    use currency::Money;
+
+    let (clock, mut auction) = auction_manual(|_| {});
+    auction.channels = vec![String::from("first"), String::from("second")];
+
+    //  Every bid records the Channel it came from, including automatic bids,
+    //      which take the Channel where the maximum was registered.
+    auction.set_proxy("alice", Money::whole(50), "first");
+    auction.bid("bob", Money::whole(20), "second");
+    auction.bid("Bob", Money::whole(60), "first");
+
+    let channels: Vec<Option<&str>> = auction.bids.iter()
+        .map(|bid| bid.channel.as_deref())
+        .collect();
+    assert_eq!(channels, [Some("first"), Some("second"), Some("first"), Some("first")]);
+
+    clock.advance(Duration::from_secs(200));
+    let winner = auction.winner().unwrap();
+    assert_eq!(winner.name, "Bob");
+    assert_eq!(winner.channel.as_deref(), Some("first"));
+    assert_eq!(auction.finish().channels, ["first", "second"]);
+
+    //  An Auction that is not shared does not record Channels at all.
+    let (_, mut auction) = auction_manual(|_| {});
+    auction.bid("alice", Money::whole(10), "first");
+    assert_eq!(auction.bids[0].channel, None);
+}
+
+
+#[test]
+fn test_snapshot() {
+    use std::time::Duration;
+    use bot::{auction::PauseReason, clock::Clock};
+    use currency::Money;
+    use saving::Snapshot;
+
+    //  Write a Snapshot as it would be written to its file, and read it back.
+    let reload = |snapshot: Snapshot| -> Snapshot {
+        let text: String = toml::to_string(&toml::Value::try_from(&snapshot).unwrap()).unwrap();
+        toml::from_str(&text).unwrap()
+    };
+
+    let (clock, mut auction) = auction_manual(|_| {});
+
+    auction.set_proxy("alice", Money::whole(30), "");
+    auction.bid("bob", Money::whole(20), "");
+    clock.advance(Duration::from_secs(30));
+
+    //  The moments in a Snapshot are read from the Clock of the Auction.
+    let snapshot: Snapshot = reload(Snapshot::new(&auction));
+    assert_eq!(snapshot.saved, clock.system());
+    assert_eq!(snapshot.opened + Duration::from_secs(30), snapshot.saved);
+    assert_eq!(snapshot.remaining, Duration::from_secs(150));
+    assert!(!snapshot.paused);
+
+    //  The Auction carries on from where it was, with the time it was away
+    //      recorded as a pause.
+    clock.advance(Duration::from_secs(20));
+    let mut recovered = snapshot.restore(clock.clone());
+    assert_eq!(recovered.remaining(), Some(Duration::from_secs(150)));
+    assert_eq!(recovered.paused(), None);
+
+    let pause = recovered.pauses.last().unwrap();
+    assert_eq!((pause.reason, pause.duration), (PauseReason::Restart, 20));
+    assert_eq!(recovered.bids.len(), auction.bids.len());
+    assert_eq!(recovered.last_bid().unwrap().bidder, "alice");
+
+    //  Maximum bids are kept, and still answer new bids.
+    recovered.bid("bob", Money::whole(25), "");
+    assert_eq!(recovered.last_bid().unwrap().amount, Money::whole(26));
+
+    clock.advance(Duration::from_secs(150));
+    let winner = recovered.winner().unwrap();
+    assert_eq!((winner.name.as_str(), winner.amount), ("alice", Money::whole(26)));
+
+    //  An Auction paused by an operator stays paused.
+    let (clock, mut auction) = auction_manual(|_| {});
+    clock.advance(Duration::from_secs(30));
+    assert!(auction.pause(PauseReason::Operator, Some(String::from("mod"))));
+
+    let snapshot: Snapshot = reload(Snapshot::new(&auction));
+    clock.advance(Duration::from_secs(20));
+    let recovered = snapshot.restore(clock);
+    assert_eq!(recovered.paused(), Some(PauseReason::Operator));
+    assert_eq!(recovered.remaining(), Some(Duration::from_secs(150)));
+}